@@ -0,0 +1,131 @@
+// writes assets/ogt_reference: meshes of the C ogt_voxel_meshify that src/renderer/ogt.rs is checked against
+// build and run from repo root:
+//   c++ -O2 deprecated/ogt_reference.cpp -o ogt_reference && ./ogt_reference
+// volumes have to stay the same as reference_volumes in ogt.rs tests
+
+#include "ogt_voxel_meshify.cpp"
+
+#include <stdio.h>
+#include <string>
+#include <vector>
+
+struct Volume {
+    std::string name;
+    std::vector<uint8_t> voxels;
+    uint32_t size[3];
+};
+
+static Volume lone_voxel() {
+    Volume volume = {"lone", std::vector<uint8_t>(27, 0), {3, 3, 3}};
+    volume.voxels[13] = 1;
+    return volume;
+}
+
+static Volume noise() {
+    Volume volume = {"noise", {}, {7, 6, 5}};
+    uint32_t state = 12345;
+    for (int i = 0; i < 7 * 6 * 5; i++) {
+        state = state * 1664525u + 1013904223u;
+        uint32_t r = state >> 24;
+        volume.voxels.push_back(r < 102 ? (uint8_t)(r % 4 + 1) : 0);
+    }
+    return volume;
+}
+
+static Volume slab() {
+    Volume volume = {"slab", {}, {12, 11, 4}};
+    for (int i = 0; i < 12 * 11 * 4; i++) {
+        int x = i % 12, y = i / 12 % 11, z = i / (12 * 11);
+        bool hole = (x % 7 == 3 && y % 5 != 0) || ((x + y) % 9 == 0 && z > 0);
+        volume.voxels.push_back(z > 2 || hole ? 0 : (uint8_t)(1 + (x / 5 + y / 6) % 3));
+    }
+    return volume;
+}
+
+static uint32_t read_u32(const std::vector<uint8_t>& data, size_t offset) {
+    return data[offset] | data[offset + 1] << 8 | data[offset + 2] << 16 | (uint32_t)data[offset + 3] << 24;
+}
+
+// every SIZE / XYZI pair of .vox in file order (same as dot_vox models), voxel value is its palette index, 0 made 1
+static void vox_models(const char* path, std::vector<Volume>& out) {
+    std::vector<uint8_t> data;
+    FILE* file = fopen(path, "rb");
+    if (!file) {
+        fprintf(stderr, "can not open %s\n", path);
+        exit(1);
+    }
+    for (int c; (c = fgetc(file)) != EOF;) {
+        data.push_back((uint8_t)c);
+    }
+    fclose(file);
+
+    // "VOX " and version, then MAIN header, its children follow
+    uint32_t size[3] = {};
+    int models = 0;
+    for (size_t offset = 8 + 12; offset + 12 <= data.size();) {
+        std::string id(data.begin() + offset, data.begin() + offset + 4);
+        uint32_t content = read_u32(data, offset + 4);
+        uint32_t children = read_u32(data, offset + 8);
+        size_t body = offset + 12;
+        if (id == "SIZE") {
+            for (int axis = 0; axis < 3; axis++) {
+                size[axis] = read_u32(data, body + axis * 4);
+            }
+        } else if (id == "XYZI") {
+            Volume volume = {"scene" + std::to_string(models++), std::vector<uint8_t>(size[0] * size[1] * size[2], 0), {size[0], size[1], size[2]}};
+            uint32_t count = read_u32(data, body);
+            for (uint32_t i = 0; i < count; i++) {
+                const uint8_t* v = &data[body + 4 + i * 4];
+                volume.voxels[v[0] + v[1] * size[0] + v[2] * size[0] * size[1]] = v[3] ? v[3] : 1;
+            }
+            out.push_back(volume);
+        }
+        offset = body + content + children;
+    }
+}
+
+// vertex and index count, then vertices and indices as they are in memory (little endian)
+static void write_mesh(const std::string& path, const ogt_mesh* mesh) {
+    FILE* file = fopen(path.c_str(), "wb");
+    if (!file) {
+        fprintf(stderr, "can not write %s\n", path.c_str());
+        exit(1);
+    }
+    fwrite(&mesh->vertex_count, 4, 1, file);
+    fwrite(&mesh->index_count, 4, 1, file);
+    fwrite(mesh->vertices, sizeof(ogt_mesh_vertex), mesh->vertex_count, file);
+    fwrite(mesh->indices, 4, mesh->index_count, file);
+    fclose(file);
+}
+
+int main() {
+    std::vector<Volume> volumes = {lone_voxel(), noise(), slab()};
+    vox_models("assets/scene.vox", volumes);
+
+    // different color for every index, so colors are checked too
+    ogt_mesh_rgba palette[256];
+    for (int i = 0; i < 256; i++) {
+        palette[i] = {(uint8_t)i, (uint8_t)(255 - i), (uint8_t)(i * 7), 255};
+    }
+
+    ogt_voxel_meshify_context ctx = {};
+    typedef ogt_mesh* (*Mesher)(const ogt_voxel_meshify_context*, const uint8_t*, uint32_t, uint32_t, uint32_t, const ogt_mesh_rgba*);
+    const struct { const char* name; Mesher mesher; } meshers[] = {
+        {"simple", ogt_mesh_from_paletted_voxels_simple},
+        {"greedy", ogt_mesh_from_paletted_voxels_greedy},
+        {"polygon", ogt_mesh_from_paletted_voxels_polygon},
+    };
+    for (const Volume& volume : volumes) {
+        for (const auto& m : meshers) {
+            std::string path = "assets/ogt_reference/" + volume.name + "_" + m.name;
+            ogt_mesh* mesh = m.mesher(&ctx, volume.voxels.data(), volume.size[0], volume.size[1], volume.size[2], palette);
+            write_mesh(path + ".bin", mesh);
+            ogt_mesh_remove_duplicate_vertices(&ctx, mesh);
+            write_mesh(path + "_dedup.bin", mesh);
+            ogt_mesh_smooth_normals(&ctx, mesh);
+            write_mesh(path + "_smooth.bin", mesh);
+            ogt_mesh_destroy(&ctx, mesh);
+        }
+    }
+    return 0;
+}
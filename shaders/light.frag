@@ -0,0 +1,37 @@
+#version 460
+#extension GL_GOOGLE_include_directive : require
+
+//lighting pass: reads G-buffer written by v.frag and traces shadow rays through voxel volume
+
+//see LightPushConstants in renderer/mod.rs
+layout(push_constant) uniform PushConstants {
+    ivec4 volume_origin; //in blocks
+    ivec4 volume_size; //in blocks
+} pco;
+
+#include "voxel.glsl"
+
+layout(input_attachment_index = 0, set = 1, binding = 0) uniform subpassInput pos_mat;
+layout(input_attachment_index = 1, set = 1, binding = 1) uniform subpassInput norm;
+
+layout(location = 0) out vec4 f_color;
+
+//see v.frag
+const uint MAT_FROM_VOLUME = 256;
+
+void main() {
+    vec4 position = subpassLoad(pos_mat);
+    uint id = uint(position.w);
+    if (id == 0) {
+        f_color = vec4(SKY, 1.0);
+        return;
+    }
+    vec4 normal_ao = subpassLoad(norm);
+    vec3 normal = normal_ao.xyz;
+    if (id == MAT_FROM_VOLUME) {
+        //voxel face belongs to is half a voxel behind it
+        id = get_voxel_at(ivec3(floor(position.xyz - normal * 0.5)));
+    }
+    //step a bit out of the surface so shadow ray does not hit voxel it starts on
+    f_color = vec4(shade_surface(id, normal, position.xyz + normal * 1e-3, normal_ao.w), 1.0);
+}
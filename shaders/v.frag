@@ -1,27 +1,20 @@
 #version 460
 
-layout(location=0) in vec3 pos_mid;
-// layout(location=1) in vec3  norm;
-// layout(location=2) in float mat;
+layout(location = 0) in vec3 world_pos;
+layout(location = 1) in vec3 world_normal;
+layout(location = 2) flat in uint voxel_id;
+layout(location = 3) in float ambient;
 
+//w is VoxelID, 0 (clear value) means nothing was drawn
+//MAT_FROM_VOLUME means lighting pass has to look color up, face was merged across colors
 layout(location = 0) out vec4 pos_mat_out;
-// layout(location = 1) out vec4 norm_out;
+//w is ambient occlusion
+layout(location = 1) out vec4 norm_out;
+
+//same as in light.frag. Vertices have mesher::MAT_FROM_VOLUME (0) for it, but 0 is taken by clear value here
+const uint MAT_FROM_VOLUME = 256;
 
 void main() {
-    // pos_mat_out == vec4(pos, mat);
-    // vec3 v = pow(pos, vec3(4.0));
-    // vec3 v;
-    // if (pos.x < 0.33) {
-    //     v.x = .6;
-    // } else if (pos.x > 0.1) {
-    //     v.y = .2;
-    // }
-    // if (pos.x < 0.66) {
-    //     v.z = .88;
-    // } else if (pos.x > .22) {
-    //     v.y += 0.1;
-    // }
-    pos_mat_out = vec4(pos_mid, 1.0);
-    // norm_out = vec4(norm, 1);
-    // f_color = vec4(1.0, 0.0, 0.0, 1.0);
-}
\ No newline at end of file
+    pos_mat_out = vec4(world_pos, float(voxel_id == 0 ? MAT_FROM_VOLUME : voxel_id));
+    norm_out = vec4(normalize(world_normal), ambient);
+}
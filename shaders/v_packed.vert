@@ -0,0 +1,33 @@
+#version 460
+
+//G-buffer pass for PackedVertex, same as v.vert otherwise. See VertexFormat in renderer/mod.rs
+
+layout(location = 0) in uvec4 position_normal; //x, y, z, index into NORMALS (low 3 bits) and ao above it
+layout(location = 1) in uint mat;
+
+//see PushConstants in renderer/mod.rs
+layout(push_constant) uniform PushConstants {
+    mat4 trans; //chunk-local -> world
+    mat4 view_proj; //world -> clip, from Camera
+} pco;
+
+layout(location = 0) out vec3 world_pos;
+layout(location = 1) out vec3 world_normal;
+layout(location = 2) flat out uint voxel_id;
+layout(location = 3) out float ambient;
+
+//same as NORMALS in renderer/mod.rs
+const vec3 NORMALS[6] = vec3[6](
+    vec3(1, 0, 0), vec3(-1, 0, 0),
+    vec3(0, 1, 0), vec3(0, -1, 0),
+    vec3(0, 0, 1), vec3(0, 0, -1)
+);
+
+void main() {
+    world_pos = (pco.trans * vec4(vec3(position_normal.xyz), 1.0)).xyz;
+    world_normal = mat3(pco.trans) * NORMALS[position_normal.w & 7u];
+    voxel_id = mat;
+    //same as v.vert
+    ambient = 0.4 + 0.2 * float(position_normal.w >> 3);
+    gl_Position = pco.view_proj * vec4(world_pos, 1.0);
+}
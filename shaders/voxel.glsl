@@ -0,0 +1,159 @@
+//voxel volume (see VolumeGPU in renderer/tracer.rs), its traversal and shading
+//shared by trace.comp and light.frag. Same traversal as trace() in renderer/tracer.rs, keep them in sync
+//includer has to declare pco with ivec4 volume_origin and volume_size (in blocks) before including
+
+//see MaterialGPU in renderer/mod.rs
+struct Material {
+    vec4 color;
+    float emmitance;
+    float roughness;
+    float metalness;
+    float ior;
+    float transparency;
+};
+//indexed by VoxelID, 0 is empty
+layout(set = 0, binding = 0, std430) readonly buffer VoxelPalette {
+    Material voxel_palette[256];
+};
+//block ids of loaded chunks (R16_UINT, see BLOCK_ID_FORMAT), volume_size big, starts at volume_origin
+layout(set = 0, binding = 1) uniform usampler3D united_blocks;
+//every block of block_palette, block b is at (b % ATLAS_ROW, b / ATLAS_ROW % ATLAS_ROW, b / ATLAS_LAYER) * BLOCK_SIZE
+layout(set = 0, binding = 2) uniform usampler3D block_palette;
+
+const int BLOCK_SIZE = 16;
+const uint ATLAS_ROW = 16;
+const uint ATLAS_LAYER = ATLAS_ROW * ATLAS_ROW;
+const float FAR = 1e30;
+const vec3 SUN = normalize(vec3(0.4, 0.3, 1.0));
+const vec3 SKY = vec3(0.55, 0.7, 0.9);
+
+struct Ray {
+    vec3 origin;
+    vec3 direction; //normalized, no zero components
+};
+
+struct Hit {
+    float t;
+    ivec3 voxel;
+    ivec3 normal;
+    uint id; //0 if nothing was hit
+};
+
+//Amanatides & Woo grid walk
+struct Dda {
+    ivec3 cell;
+    ivec3 step_;
+    vec3 t_max;
+    vec3 t_delta;
+    float t;
+    ivec3 normal;
+};
+
+Ray make_ray(vec3 origin, vec3 direction) {
+    direction = normalize(direction);
+    //zero components would make slab test and DDA divide 0 by 0
+    direction = mix(direction, vec3(1e-6), lessThan(abs(direction), vec3(1e-6)));
+    return Ray(origin, direction);
+}
+
+Dda dda_new(Ray ray, float cell_size, float t, ivec3 lo, ivec3 hi, ivec3 normal) {
+    Dda d;
+    d.cell = clamp(ivec3(floor((ray.origin + ray.direction * t) / cell_size)), lo, hi);
+    d.step_ = ivec3(sign(ray.direction));
+    vec3 next_boundary = vec3(d.cell + max(d.step_, ivec3(0))) * cell_size;
+    d.t_max = (next_boundary - ray.origin) / ray.direction;
+    d.t_delta = abs(cell_size / ray.direction);
+    d.t = t;
+    d.normal = normal;
+    return d;
+}
+
+void dda_next(inout Dda d) {
+    int axis = (d.t_max.x < d.t_max.y && d.t_max.x < d.t_max.z) ? 0 : (d.t_max.y < d.t_max.z) ? 1 : 2;
+    d.t = d.t_max[axis];
+    d.t_max[axis] += d.t_delta[axis];
+    d.cell[axis] += d.step_[axis];
+    d.normal = ivec3(0);
+    d.normal[axis] = -d.step_[axis];
+}
+
+bool outside(ivec3 p, ivec3 lo, ivec3 hi) {
+    return any(lessThan(p, lo)) || any(greaterThanEqual(p, hi));
+}
+
+uint get_block(ivec3 pos) {
+    return texelFetch(united_blocks, pos - pco.volume_origin.xyz, 0).r;
+}
+
+uint get_voxel(uint block, ivec3 local) {
+    //same as atlas_offset in renderer/tracer.rs
+    ivec3 atlas = ivec3(block % ATLAS_ROW, block / ATLAS_ROW % ATLAS_ROW, block / ATLAS_LAYER) * BLOCK_SIZE;
+    return texelFetch(block_palette, atlas + local, 0).r;
+}
+
+//voxel at pos (in voxels), 0 outside of volume
+uint get_voxel_at(ivec3 pos) {
+    ivec3 block = ivec3(floor(vec3(pos) / BLOCK_SIZE));
+    if (outside(block, pco.volume_origin.xyz, pco.volume_origin.xyz + pco.volume_size.xyz)) {
+        return 0;
+    }
+    return get_voxel(get_block(block), pos - block * BLOCK_SIZE);
+}
+
+Hit trace(Ray ray, float max_t) {
+    Hit miss = Hit(FAR, ivec3(0), ivec3(0), 0u);
+    ivec3 lo = pco.volume_origin.xyz;
+    ivec3 hi = pco.volume_origin.xyz + pco.volume_size.xyz;
+
+    //clip ray to volume
+    vec3 t0 = (vec3(lo * BLOCK_SIZE) - ray.origin) / ray.direction;
+    vec3 t1 = (vec3(hi * BLOCK_SIZE) - ray.origin) / ray.direction;
+    vec3 t_near = min(t0, t1);
+    vec3 t_far = max(t0, t1);
+    float t_near_max = max(t_near.x, max(t_near.y, t_near.z));
+    float t_enter = max(t_near_max, 0.0);
+    float t_exit = min(min(t_far.x, min(t_far.y, t_far.z)), max_t);
+    if (t_enter >= t_exit) {
+        return miss;
+    }
+    ivec3 normal = ivec3(0);
+    if (t_near_max > 0.0) {
+        int axis = (t_near.x > t_near.y && t_near.x > t_near.z) ? 0 : (t_near.y > t_near.z) ? 1 : 2;
+        normal[axis] = -int(sign(ray.direction[axis]));
+    }
+
+    Dda blocks = dda_new(ray, float(BLOCK_SIZE), t_enter, lo, hi - 1, normal);
+    while (true) {
+        uint block = get_block(blocks.cell);
+        if (block != 0) {
+            ivec3 base = blocks.cell * BLOCK_SIZE;
+            Dda voxels = dda_new(ray, 1.0, blocks.t, base, base + BLOCK_SIZE - 1, blocks.normal);
+            while (true) {
+                if (voxels.t >= t_exit) {
+                    return miss;
+                }
+                uint voxel = get_voxel(block, voxels.cell - base);
+                if (voxel != 0) {
+                    return Hit(voxels.t, voxels.cell, voxels.normal, voxel);
+                }
+                dda_next(voxels);
+                if (outside(voxels.cell - base, ivec3(0), ivec3(BLOCK_SIZE))) {
+                    break;
+                }
+            }
+        }
+        dda_next(blocks);
+        if (blocks.t >= t_exit || outside(blocks.cell, lo, hi)) {
+            return miss;
+        }
+    }
+}
+
+//ambient + sun with hard shadow (traced from point just outside of surface) + emission
+//ambient scales sky light, 1.0 when there is no baked occlusion
+vec3 shade_surface(uint id, vec3 normal, vec3 point, float ambient) {
+    Material material = voxel_palette[id];
+    float lit = trace(make_ray(point, SUN), FAR).id == 0 ? 1.0 : 0.0;
+    float diffuse = max(dot(normal, SUN), 0.0) * lit;
+    return material.color.rgb * (0.25 * ambient * SKY + 0.75 * diffuse) + material.color.rgb * material.emmitance;
+}
@@ -4,23 +4,25 @@ extern crate exr;
 extern crate fps_counter;
 extern crate glam;
 extern crate block_mesh;
+extern crate dot_vox;
+extern crate image;
 
-use std::{io::empty, mem::MaybeUninit, ops::{Deref, Sub}, sync::Arc};
+use std::{io::empty, mem::MaybeUninit, ops::{Deref, Sub}, sync::Arc, time::Instant};
 
 use block_mesh::{ndshape::ConstShape3u32, GreedyQuadsBuffer, MergeVoxel, OrientedBlockFace, UnitQuadBuffer, Voxel, VoxelVisibility, RIGHT_HANDED_Y_UP_CONFIG};
 use fps_counter::FPSCounter;
 
-use glam::{IVec3, Mat4};
-use vulkano::{buffer::{Buffer, BufferCreateInfo, BufferUsage, Subbuffer}, image::Image};
+use glam::{IVec3, Mat4, Vec2, Vec3};
+use vulkano::{buffer::Subbuffer, image::Image};
 use vulkano::command_buffer::allocator::{StandardCommandBufferAllocator, StandardCommandBufferAllocatorCreateInfo};
-use vulkano::command_buffer::{AutoCommandBufferBuilder, CommandBufferUsage, CopyBufferInfo, PrimaryCommandBufferAbstract};
+use vulkano::descriptor_set::allocator::StandardDescriptorSetAllocator;
 use vulkano::device::{DeviceExtensions};
-use vulkano::memory::allocator::{AllocationCreateInfo, MemoryTypeFilter, StandardMemoryAllocator};
+use vulkano::memory::allocator::StandardMemoryAllocator;
 use vulkano::pipeline::graphics::viewport::Viewport;
 use vulkano::swapchain::{self, SwapchainCreateInfo, SwapchainPresentInfo};
 use vulkano::sync::future::FenceSignalFuture;
 use vulkano::sync::{self, GpuFuture};
-use vulkano::{DeviceSize, Validated, VulkanError};
+use vulkano::{Validated, VulkanError};
 use winit::{event::{Event, WindowEvent}};
 use winit::event_loop::{ControlFlow, EventLoop};
 
@@ -28,23 +30,198 @@ mod renderer;
 use renderer::*;
 use renderer::loader::*;
 use renderer::world::*;
-
-use crate::ogt_voxel_meshify::ogt_mesh;
+use renderer::camera::Camera;
+use renderer::controls::{CameraController, ControlMode};
 
 // const VISIBLE_WORLD: usize = 8;
 
+///usage: vk-rs [path/to/scene.vox] [--mesher simple|greedy|polygon|simple-smooth|block-mesh-greedy|block-mesh-greedy-across-colors|block-mesh-visible]
+///             [--headless out.png|out.exr] [--size WIDTHxHEIGHT] [--renderer raster|trace|cpu|cpu-direct] [--samples N] [--max-blocks N]
+///             [--vertex-format full|packed|streamed] [--bench] [--mesh-threads N] [--projection isometric|perspective]
+struct Args {
+    scene: String,
+    mesher: mesher::Mesher,
+    renderer: Renderer,
+    ///paths per pixel for Renderer::Cpu
+    samples: u32,
+    ///render one frame offscreen into this file instead of opening window
+    headless: Option<String>,
+    size: [u32; 2],
+    ///World::max_blocks, loading fails with more distinct blocks than that
+    max_blocks: usize,
+    ///what chunk meshes are uploaded as
+    vertex_format: VertexFormat,
+    ///print vertex format and streaming benchmarks for scene instead of rendering
+    bench: bool,
+    ///window meshes in background on this many threads, 0 meshes whole scene before it opens. Headless never does
+    mesh_threads: usize,
+    ///what camera starts as
+    projection: StartProjection,
+}
+
+///how frame is made
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Renderer {
+    ///draws chunk meshes
+    Raster,
+    ///ray traces voxels with trace.comp, headless only for now
+    Trace,
+    ///path traces on CPU, no Vulkan needed. Headless only
+    Cpu,
+    ///one ray per pixel on CPU, shaded like trace.comp. Headless only
+    CpuDirect,
+}
+
+impl std::str::FromStr for Renderer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "raster" => Ok(Renderer::Raster),
+            "trace" => Ok(Renderer::Trace),
+            "cpu" => Ok(Renderer::Cpu),
+            "cpu-direct" => Ok(Renderer::CpuDirect),
+            _ => Err(format!("unknown renderer {}", s)),
+        }
+    }
+}
+
+///camera looking at whole scene from the same diagonal, Orbit zooms it either way
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StartProjection {
+    Isometric,
+    Perspective,
+}
+
+impl std::str::FromStr for StartProjection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "isometric" => Ok(StartProjection::Isometric),
+            "perspective" => Ok(StartProjection::Perspective),
+            _ => Err(format!("unknown projection {}", s)),
+        }
+    }
+}
+
+fn parse_size(s: &str) -> Result<[u32; 2], String> {
+    let (w, h) = s.split_once('x').ok_or(format!("size should look like 1920x1080, got {}", s))?;
+    let parse = |v: &str| v.parse::<u32>().map_err(|e| format!("bad size {}: {}", s, e));
+    Ok([parse(w)?, parse(h)?])
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        scene: "assets/scene.vox".to_string(),
+        mesher: mesher::Mesher::OgtSimple,
+        renderer: Renderer::Raster,
+        samples: 16,
+        headless: None,
+        size: [1920, 1080],
+        max_blocks: MAX_BLOCKS,
+        vertex_format: VertexFormat::Full,
+        bench: false,
+        mesh_threads: jobs::MeshWorkers::default_threads(),
+        projection: StartProjection::Isometric,
+    };
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--mesher" => args.mesher = iter.next().ok_or("--mesher needs a value")?.parse()?,
+            "--renderer" => args.renderer = iter.next().ok_or("--renderer needs a value")?.parse()?,
+            "--samples" => args.samples = iter.next().ok_or("--samples needs a value")?.parse().map_err(|e| format!("bad --samples: {}", e))?,
+            "--headless" => args.headless = Some(iter.next().ok_or("--headless needs output path")?),
+            "--size" => args.size = parse_size(&iter.next().ok_or("--size needs a value")?)?,
+            "--vertex-format" => args.vertex_format = iter.next().ok_or("--vertex-format needs a value")?.parse()?,
+            "--bench" => args.bench = true,
+            "--mesh-threads" => args.mesh_threads = iter.next().ok_or("--mesh-threads needs a value")?.parse().map_err(|e| format!("bad --mesh-threads: {}", e))?,
+            "--projection" => args.projection = iter.next().ok_or("--projection needs a value")?.parse()?,
+            "--max-blocks" => args.max_blocks = iter.next().ok_or("--max-blocks needs a value")?.parse().map_err(|e| format!("bad --max-blocks: {}", e))?,
+            _ if arg.starts_with("--") => return Err(format!("unknown option {}", arg)),
+            _ => args.scene = arg,
+        }
+    }
+    if args.max_blocks > MAX_BLOCKS {
+        return Err(format!("--max-blocks can be at most {} with this BlockIndex", MAX_BLOCKS));
+    }
+    if args.renderer != Renderer::Raster && args.headless.is_none() {
+        return Err("--renderer trace, cpu and cpu-direct only work with --headless for now".to_string());
+    }
+    Ok(args)
+}
+
 fn main() {
-    println!("lmao");
+    let args = parse_args().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+
+    if args.bench {
+        if let Err(e) = bench::vertex_formats(&args.scene).and_then(|_| bench::streaming(&args.scene)) {
+            eprintln!("{}: {}", args.scene, e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let mut world = World::new();
+    world.mesher = args.mesher;
+    world.max_blocks = args.max_blocks;
+    //headless renders right after loading, so it needs every mesh by then
+    if args.headless.is_none() && args.mesh_threads > 0 {
+        world.workers = Some(jobs::MeshWorkers::new(args.mesh_threads));
+    }
+    let scene = load_vox(&args.scene).and_then(|scene| world.load_map(&scene));
+    if let Err(e) = scene {
+        eprintln!("{}: {}", args.scene, e);
+        std::process::exit(1);
+    }
+    if let Some(workers) = &world.workers {
+        println!("{:?}: meshing {} blocks on {} threads", world.mesher, world.meshing(), workers.threads());
+    } else {
+        let triangles: usize = world.chunks.values().map(|chunk| chunk.mesh.indices.len() / 3).sum();
+        let vertices: usize = world.chunks.values().map(|chunk| chunk.mesh.vertices.len()).sum();
+        println!("{:?}: {} triangles, {} vertices", world.mesher, triangles, vertices);
+    }
     println!("lmao");
 
-    let a: ogt_mesh;
-    
-    // world.load_map();
-    world.load_map_ogt();
-    println!("lmao");
+    //cpu renderers have to work without Vulkan SDK
+    if !matches!(args.renderer, Renderer::Cpu | Renderer::CpuDirect) {
+        compile_shaders();
+    }
+
+    //whole scene in view, meshes may not be there yet but their blocks are
+    let (min, max) = world.mesh_bounds().or(world.block_bounds()).unwrap_or((Vec3::ZERO, Vec3::ONE));
+    let (center, extent) = ((min + max) / 2.0, (max - min).length());
+    let mut camera = match args.projection {
+        StartProjection::Isometric => Camera::isometric(center, extent, 1.0),
+        //60 degree fov fits scene diagonal from this far
+        StartProjection::Perspective => Camera::perspective(center + Vec3::ONE.normalize() * extent, center, 1.0),
+    };
 
-    compile_shaders();
+    if let Some(output) = &args.headless {
+        camera.set_extent(args.size);
+        let pixels = match args.renderer {
+            Renderer::Raster => {
+                let mut renderer = headless::HeadlessRenderer::new();
+                renderer.vertex_format = args.vertex_format;
+                renderer.render(&world, &camera, args.size)
+            }
+            Renderer::Trace => headless::HeadlessRenderer::new().trace(&world, &camera, args.size),
+            Renderer::Cpu => Ok(cpu_tracer::render_path_traced(&world, &camera, args.size, args.samples, 4).into_raw()),
+            Renderer::CpuDirect => Ok(cpu_tracer::render_direct(&world, &camera, args.size).into_raw()),
+        };
+        let pixels = pixels.unwrap_or_else(|e| {
+            eprintln!("{}: {}", args.scene, e);
+            std::process::exit(1);
+        });
+        if let Err(e) = headless::save_frame(std::path::Path::new(output), args.size, &pixels) {
+            eprintln!("{}: {}", output, e);
+            std::process::exit(1);
+        }
+        return;
+    }
     
     let event_loop = EventLoop::new();
     let instance = create_instance(&event_loop);
@@ -67,128 +244,80 @@ fn main() {
     let (mut swapchain, swapchain_images) = create_swapchain(physical_device.clone(), device.clone(), surface.clone(), window.clone());
 
     let memory_allocator = Arc::new(StandardMemoryAllocator::new_default(device.clone()));
-    // let raygen_pos_mat_images = Image::new(memory_allocator.clone(), 
-    // ImageCreateInfo {
-    //     // image_type
-    //     image_type: ImageType::Dim2d,
-    //     format: vulkano::format::Format::R32G32B32A32_SFLOAT,
-    //     extent: [swapchain.image_extent()[0], swapchain.image_extent()[1], 1],
-    //     usage: ImageUsage::COLOR_ATTACHMENT,
-    //     ..Default::default(
-    // )}, 
-    // AllocationCreateInfo {
-    //     ..Default::default()
-    // });
-    // let raygen_norm_images = Image::new(memory_allocator.clone(), 
-    // ImageCreateInfo {
-    //     // image_type
-    //     image_type: ImageType::Dim2d,
-    //     format: vulkano::format::Format::R32G32B32A32_SFLOAT,
-    //     extent: [swapchain.image_extent()[0], swapchain.image_extent()[1], 1],
-    //     usage: ImageUsage::COLOR_ATTACHMENT,
-    //     ..Default::default(
-    // )}, 
-    // AllocationCreateInfo {
-    //     ..Default::default()
-    // });
-
-    let present_render_pass  = get_render_pass(device.clone(), swapchain.clone());
-    let present_framebuffers = get_framebuffers(&swapchain_images, present_render_pass.clone(), memory_allocator.clone());
-
-    let l = world.chunks[0].mesh.vertices.len();
-
-    let vertex_buffer = Buffer::from_iter(
-        memory_allocator.clone(),
-        BufferCreateInfo {
-            usage: BufferUsage::TRANSFER_SRC,
-            ..Default::default()
-        },
-        AllocationCreateInfo {
-            memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
-                | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
-            ..Default::default()
-        },
-        world.chunks[0].mesh.vertices.clone(),
-    ).unwrap();
-
-    let local_vertex_buffer = Buffer::new_slice::<MyVertex>(
-        memory_allocator.clone(), 
-        BufferCreateInfo {
-            usage: BufferUsage::VERTEX_BUFFER | BufferUsage::TRANSFER_DST,
-            ..Default::default()
-        },
-        AllocationCreateInfo {
-            memory_type_filter: MemoryTypeFilter::PREFER_DEVICE,
-            ..Default::default()
-        },
-        l as DeviceSize
-    ).unwrap();
+    let present_render_pass  = get_render_pass(device.clone(), swapchain.image_format());
+    let mut present_framebuffers = get_framebuffers(&swapchain_images, present_render_pass.clone(), memory_allocator.clone());
 
     let command_buffer_allocator = StandardCommandBufferAllocator::new(
         device.clone(),
         StandardCommandBufferAllocatorCreateInfo::default(),
     );
 
-    // Create a one-time command to copy between the buffers.
-    let mut cbb = AutoCommandBufferBuilder::primary(
-        &command_buffer_allocator,
-        queue.queue_family_index(),
-        CommandBufferUsage::OneTimeSubmit,
-    )
-    .unwrap();
-    cbb.copy_buffer(CopyBufferInfo::buffers(
-            vertex_buffer,
-            local_vertex_buffer.clone(),
-        ))
-        .unwrap();
-    let one_time_cb = cbb.build().unwrap();
-
-    // Execute the copy command and wait for completion before proceeding.
-    one_time_cb.execute(queue.clone())
-        .unwrap()
-        .then_signal_fence_and_flush()
-        .unwrap()
-        .wait(None /* timeout */)
-        .unwrap();
-    
+    let mut chunk_buffers = upload_world(memory_allocator.clone(), &command_buffer_allocator, &queue, &world, args.vertex_format);
 
-    let vs = load_shader(device.clone(), "shaders/vert.spv");
+    //edits must not grow block palette past what block atlas of this GPU holds
+    world.max_blocks = world.max_blocks.min(tracer::atlas_capacity(&device));
+    let mut volume = tracer::upload_volume(memory_allocator.clone(), &command_buffer_allocator, &queue, &world).unwrap_or_else(|e| {
+        eprintln!("{}: {}", args.scene, e);
+        std::process::exit(1);
+    });
+
+    let vs = load_shader(device.clone(), args.vertex_format.vertex_shader());
     let fs = load_shader(device.clone(), "shaders/frag.spv");
+    let light_vs = load_shader(device.clone(), "shaders/light_vert.spv");
+    let light_fs = load_shader(device.clone(), "shaders/light_frag.spv");
 
     let mut viewport = Viewport {
         offset: [0.0, 0.0],
         extent: window.inner_size().into(),
         depth_range: 0.0..=1.0,
     };
+    camera.set_extent(window.inner_size().into());
 
-    let pipeline = get_graphical_pipeline(
+    let mut pipeline = get_graphical_pipeline(
         device.clone(),
         vs.clone(),
         fs.clone(),
         present_render_pass.clone(),
         viewport.clone(),
+        args.vertex_format,
+    );
+    let mut lighting_pipeline = get_lighting_pipeline(
+        device.clone(),
+        light_vs.clone(),
+        light_fs.clone(),
+        present_render_pass.clone(),
+        viewport.clone(),
     );
 
     let command_buffer_allocator =
         StandardCommandBufferAllocator::new(device.clone(), Default::default());
+    let descriptor_set_allocator =
+        StandardDescriptorSetAllocator::new(device.clone(), Default::default());
 
     let mut command_buffers = get_command_buffers(
         &command_buffer_allocator,
+        &descriptor_set_allocator,
         &queue,
-        &pipeline,
-        &present_framebuffers,
-        &local_vertex_buffer,
+        &DrawTargets {pipeline: &pipeline, lighting_pipeline: &lighting_pipeline, framebuffers: &present_framebuffers, volume: &volume},
+        &chunk_buffers,
+        &camera,
     );
 
     let mut window_resized = false;
     let mut recreate_swapchain = false;
 
+    //Tab switches to Fly
+    let mut controller = CameraController::new(ControlMode::Orbit, center);
+    let mut last_frame = Instant::now();
+
     let frames_in_flight = swapchain_images.len();
     let mut swapchain_fences: Vec<Option<Arc<FenceSignalFuture<_>>>> = vec![None; frames_in_flight];
     let mut previous_fence_i = 0;
 
     // let fps = fps_counter;
     let mut fps_counter = FPSCounter::new();
+    //until background meshing of loaded scene is done
+    let mut meshing_since = world.workers.as_ref().map(|_| Instant::now());
 
     event_loop.run(move |event, _, control_flow| match event {
         Event::WindowEvent {event: WindowEvent::CloseRequested, ..} => {
@@ -199,7 +328,64 @@ fn main() {
         Event::WindowEvent {event: WindowEvent::Resized(_), .. } => {
             window_resized = true;
         }
+        Event::WindowEvent {event, .. } => {
+            controller.handle_window_event(&event);
+        }
+        Event::DeviceEvent {event, .. } => {
+            controller.handle_device_event(&event);
+        }
         Event::MainEventsCleared => {
+            let now = Instant::now();
+            let dt = (now - last_frame).as_secs_f32();
+            last_frame = now;
+            //view_proj is baked into command buffers as push constant
+            let mut rerecord = controller.update(&mut camera, dt);
+
+            //keeps chunks within WORLD_SIZE/2 of what camera looks at loaded
+            let changes = world.stream_around(World::chunk_at(controller.focus(&camera)));
+            if !changes.is_empty() {
+                for fence in swapchain_fences.iter().flatten() {
+                    fence.wait(None).unwrap();
+                }
+                let positions: Vec<IVec3> = changes.loaded.iter().chain(&changes.unloaded).copied().collect();
+                update_chunks(memory_allocator.clone(), &command_buffer_allocator, &queue, &world, &positions, args.vertex_format, &mut chunk_buffers);
+                let blocks: Vec<IVec3> = positions.iter().flat_map(|pos| World::chunk_blocks(*pos)).collect();
+                if let Err(e) = tracer::update_volume(memory_allocator.clone(), &command_buffer_allocator, &queue, &mut volume, &world, &blocks) {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+                rerecord = true;
+            }
+
+            //Q / E at whatever is in the middle of view, remesh_dirty below brings it to GPU
+            if let Some(edit) = controller.take_edit() {
+                let (origin, direction) = camera.ray(Vec2::ZERO);
+                if let Err(e) = world.edit_at(&tracer::Ray::new(origin, direction), edit) {
+                    eprintln!("{}", e);
+                }
+            }
+
+            //edits (World::set_voxel) since last frame and meshes workers finished meanwhile
+            let remeshed = world.remesh_dirty();
+            if let (Some(since), Some(workers)) = (meshing_since, &world.workers) {
+                if world.meshing() == 0 {
+                    println!("meshed in {:.2?}, ogt used at most {} KB per thread", since.elapsed(), workers.peak_bytes() / 1024);
+                    meshing_since = None;
+                }
+            }
+            if !remeshed.is_empty() {
+                //volume images are updated in place, so frames still reading them have to finish
+                for fence in swapchain_fences.iter().flatten() {
+                    fence.wait(None).unwrap();
+                }
+                update_chunks(memory_allocator.clone(), &command_buffer_allocator, &queue, &world, &remeshed.chunks, args.vertex_format, &mut chunk_buffers);
+                if let Err(e) = tracer::update_volume(memory_allocator.clone(), &command_buffer_allocator, &queue, &mut volume, &world, &remeshed.blocks) {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+                rerecord = true;
+            }
+
             if window_resized || recreate_swapchain {
                 recreate_swapchain = false;
 
@@ -213,29 +399,43 @@ fn main() {
                     .expect("failed to recreate swapchain");
 
                 swapchain = new_swapchain;
-                let new_framebuffers = get_framebuffers(&new_images, present_render_pass.clone(), memory_allocator.clone());
+                present_framebuffers = get_framebuffers(&new_images, present_render_pass.clone(), memory_allocator.clone());
+                rerecord = true;
 
                 if window_resized {
                     window_resized = false;
 
                     viewport.extent = new_dimensions.into();
-                    let new_pipeline = get_graphical_pipeline(
+                    camera.set_extent(new_dimensions.into());
+                    pipeline = get_graphical_pipeline(
                         device.clone(),
                         vs.clone(),
                         fs.clone(),
                         present_render_pass.clone(),
                         viewport.clone(),
+                        args.vertex_format,
                     );
-                    command_buffers = get_command_buffers(
-                        &command_buffer_allocator,
-                        &queue,
-                        &new_pipeline,
-                        &new_framebuffers,
-                        &local_vertex_buffer,
+                    lighting_pipeline = get_lighting_pipeline(
+                        device.clone(),
+                        light_vs.clone(),
+                        light_fs.clone(),
+                        present_render_pass.clone(),
+                        viewport.clone(),
                     );
                 }
             }
 
+            if rerecord {
+                command_buffers = get_command_buffers(
+                    &command_buffer_allocator,
+                    &descriptor_set_allocator,
+                    &queue,
+                    &DrawTargets {pipeline: &pipeline, lighting_pipeline: &lighting_pipeline, framebuffers: &present_framebuffers, volume: &volume},
+                    &chunk_buffers,
+                    &camera,
+                );
+            }
+
             let (image_i, suboptimal, acquire_future) =
                 match swapchain::acquire_next_image(swapchain.clone(), None)
                     .map_err(Validated::unwrap)
@@ -0,0 +1,159 @@
+// per-vertex ambient occlusion of voxel meshes, the classic 3 neighbour one (side, side, corner)
+// AO of a face corner only depends on the 4 voxels around that corner in front of the face,
+// so faces sharing a corner get the same value and deduplicated vertices stay valid
+
+use glam::{IVec3, Vec3};
+
+use crate::mesher::{padded_index, BlockMesh};
+use crate::world::{VoxelID, BLOCK_SIZE};
+
+///AO of corner nothing occludes
+pub const AO_NONE: u8 = 3;
+
+///pos is block-local, padded block (see mesher::padded_index) covers one voxel of every neighbour around it
+fn is_solid(padded: &[VoxelID], pos: IVec3) -> bool {
+    if pos.cmplt(IVec3::NEG_ONE).any() || pos.cmpgt(IVec3::splat(BLOCK_SIZE as i32)).any() {
+        return false;
+    }
+    !padded[padded_index(pos)].is_empty()
+}
+
+///axis of normal and 1 or -1
+fn normal_axis(normal: IVec3) -> (usize, i32) {
+    let axis = if normal.x != 0 {0} else if normal.y != 0 {1} else {2};
+    (axis, normal[axis].signum())
+}
+
+///0 (fully occluded) ..= AO_NONE for corner (in voxels, block-local) of face with given axis normal
+pub fn corner_ao(padded: &[VoxelID], corner: IVec3, normal: IVec3) -> u8 {
+    let (axis, sign) = normal_axis(normal);
+    let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+    //layer of voxels face looks into
+    let mut base = corner;
+    base[axis] += if sign > 0 {0} else {-1};
+    let solid = |du: i32, dv: i32| {
+        let mut pos = base;
+        pos[u] += du;
+        pos[v] += dv;
+        is_solid(padded, pos) as u8
+    };
+    let (a, b, c, d) = (solid(-1, -1), solid(0, -1), solid(-1, 0), solid(0, 0));
+    match a + b + c + d {
+        0 => 3,
+        1 => 2,
+        //both sides of face voxel occupied (diagonal pair) close the corner completely
+        2 if a == d => 0,
+        2 => 1,
+        _ => 0,
+    }
+}
+
+///AO of the 4 corners of face of voxel at pos (block-local) facing normal
+pub fn face_ao(padded: &[VoxelID], pos: IVec3, normal: IVec3) -> [u8; 4] {
+    let (axis, sign) = normal_axis(normal);
+    let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+    let mut corner = pos;
+    corner[axis] += if sign > 0 {1} else {0};
+    [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(du, dv)| {
+        let mut c = corner;
+        c[u] += du;
+        c[v] += dv;
+        corner_ao(padded, c, normal)
+    })
+}
+
+///greedy meshers may only merge voxels with equal keys (and same material)
+/// every visible face with the same AO in all corners adds that AO, voxel with any unevenly occluded face
+/// gets key of its own, since merged quad would interpolate AO across it wrong
+/// keys are for block voxels only (BLOCK_SIZE^3, X -> Y -> Z), not the padding
+pub fn merge_keys(padded: &[VoxelID]) -> Vec<u32> {
+    let size = BLOCK_SIZE as i32;
+    let normals = [IVec3::X, IVec3::NEG_X, IVec3::Y, IVec3::NEG_Y, IVec3::Z, IVec3::NEG_Z];
+    (0..BLOCK_SIZE*BLOCK_SIZE*BLOCK_SIZE).map(|i| {
+        let pos = IVec3::new(i as i32 % size, i as i32 / size % size, i as i32 / (size*size));
+        if padded[padded_index(pos)].is_empty() {
+            return 0;
+        }
+        let mut key = 0;
+        for (face, normal) in normals.iter().enumerate() {
+            if is_solid(padded, pos + *normal) {
+                continue;
+            }
+            let ao = face_ao(padded, pos, *normal);
+            if ao.iter().any(|corner| *corner != ao[0]) {
+                return (1 << 12) + i as u32;
+            }
+            key |= ((AO_NONE - ao[0]) as u32) << (face * 2);
+        }
+        key
+    }).collect()
+}
+
+///fills MyVertex::ao of block-local mesh. Positions are voxel corners and normals axis aligned for every mesher
+pub fn bake_ao(mesh: &mut BlockMesh, padded: &[VoxelID]) {
+    for vertex in &mut mesh.vertices {
+        let corner = Vec3::from(vertex.position).round().as_ivec3();
+        let normal = Vec3::from(vertex.normal);
+        //dominant axis, in case normals ever get smoothed
+        let size = normal.abs();
+        let axis = if size.x >= size.y && size.x >= size.z {0} else if size.y >= size.z {1} else {2};
+        let mut axis_normal = IVec3::ZERO;
+        axis_normal[axis] = normal[axis].signum() as i32;
+        vertex.ao = corner_ao(padded, corner, axis_normal);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::mesher::PADDED_VOLUME;
+
+    ///padded block with given (block-local, border included) voxels solid
+    fn block(solid: &[IVec3]) -> Vec<VoxelID> {
+        let mut voxels = vec![VoxelID::EMPTY; PADDED_VOLUME];
+        for pos in solid {
+            voxels[padded_index(*pos)] = VoxelID(1);
+        }
+        voxels
+    }
+
+    #[test]
+    fn lone_voxel_is_not_occluded() {
+        let voxels = block(&[IVec3::splat(5)]);
+        assert_eq!(face_ao(&voxels, IVec3::splat(5), IVec3::Z), [AO_NONE; 4]);
+    }
+
+    #[test]
+    fn floor_next_to_wall_is_darker_at_wall() {
+        //floor at z 0, wall voxel standing on it at x 6
+        let voxels = block(&[IVec3::new(5, 5, 0), IVec3::new(6, 5, 0), IVec3::new(6, 5, 1)]);
+        //top face of floor voxel at x 5: corners at x 6 touch the wall
+        let ao = face_ao(&voxels, IVec3::new(5, 5, 0), IVec3::Z);
+        assert_eq!(ao, [AO_NONE, 2, AO_NONE, 2]);
+    }
+
+    #[test]
+    fn inner_corner_is_fully_occluded() {
+        let voxels = block(&[IVec3::new(5, 5, 0), IVec3::new(6, 5, 1), IVec3::new(5, 6, 1)]);
+        assert_eq!(corner_ao(&voxels, IVec3::new(6, 6, 1), IVec3::Z), 0);
+    }
+
+    #[test]
+    fn unevenly_occluded_voxels_do_not_share_merge_key() {
+        let voxels = block(&[IVec3::new(5, 5, 0), IVec3::new(6, 5, 0), IVec3::new(7, 5, 0), IVec3::new(7, 5, 1), IVec3::new(2, 5, 0), IVec3::new(3, 5, 0)]);
+        let keys = merge_keys(&voxels);
+        let key = |p: IVec3| keys[p.x as usize + p.y as usize*BLOCK_SIZE + p.z as usize*BLOCK_SIZE*BLOCK_SIZE];
+        //far from wall: evenly lit, same key
+        assert_eq!(key(IVec3::new(2, 5, 0)), key(IVec3::new(3, 5, 0)));
+        //next to wall: top face is darker on one side
+        assert_ne!(key(IVec3::new(6, 5, 0)), key(IVec3::new(5, 5, 0)));
+    }
+
+    #[test]
+    fn neighbouring_block_occludes_edge() {
+        //floor at block edge, wall in the next block over
+        let voxels = block(&[IVec3::new(15, 5, 0), IVec3::new(16, 5, 1)]);
+        assert_eq!(face_ao(&voxels, IVec3::new(15, 5, 0), IVec3::Z), [AO_NONE, 2, AO_NONE, 2]);
+    }
+}
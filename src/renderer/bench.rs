@@ -0,0 +1,156 @@
+// vk-rs --bench: what VertexFormat saves on a real scene. CPU only, so numbers are comparable between machines without GPU
+// memory is exact (what update_chunks uploads), bandwidth is measured by streaming vertex buffers the way vertex fetch would
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::loader::{load_vox, VoxLoadError};
+use crate::mesher::{self, Mesher};
+use crate::ogt::ogt_mesh_rgba;
+use crate::world::{VoxelID, World};
+use crate::{ogt, MyVertex, PackedVertex, VertexFormat};
+
+///passes over every vertex buffer per measurement
+const PASSES: u32 = 20;
+
+///reads every byte once, like vertex fetch reads whole buffer every frame
+fn stream<T: Copy>(buffers: &[Vec<T>]) -> Duration {
+    let start = Instant::now();
+    for _ in 0..PASSES {
+        for buffer in buffers {
+            black_box(black_box(buffer.as_slice()).to_vec());
+        }
+    }
+    start.elapsed() / PASSES
+}
+
+fn megabytes(bytes: usize) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0)
+}
+
+///prints vertex memory and streaming time of both formats for every mesher
+pub fn vertex_formats(scene: &str) -> Result<(), VoxLoadError> {
+    let scene = load_vox(scene)?;
+    println!("{:<18} {:>10} {:>10} {:>12} {:>12} {:>10} {:>12} {:>12} {:>10}",
+        "mesher", "vertices", "indices", "full MB", "packed MB", "pack us", "full us", "packed us", "saved");
+    for mesher in Mesher::ALL {
+        let mut world = World::new();
+        world.mesher = mesher;
+        world.load_map(&scene)?;
+
+        let full: Vec<Vec<MyVertex>> = world.chunks.values().map(|chunk| chunk.mesh.vertices.clone()).collect();
+        let vertices: usize = full.iter().map(Vec::len).sum();
+        let indices: usize = world.chunks.values().map(|chunk| chunk.mesh.indices.len()).sum();
+
+        let start = Instant::now();
+        let packed: Vec<Vec<PackedVertex>> = full.iter().map(|buffer| buffer.iter().map(PackedVertex::pack).collect()).collect();
+        let pack_time = start.elapsed();
+
+        let full_bytes = vertices * VertexFormat::Full.vertex_size();
+        let packed_bytes = vertices * VertexFormat::Packed.vertex_size();
+        let full_time = stream(&full);
+        let packed_time = stream(&packed);
+        println!("{:<18} {:>10} {:>10} {:>12.2} {:>12.2} {:>10.1} {:>12.1} {:>12.1} {:>9.0}%",
+            format!("{:?}", mesher), vertices, indices,
+            megabytes(full_bytes), megabytes(packed_bytes),
+            pack_time.as_secs_f64() * 1e6,
+            full_time.as_secs_f64() * 1e6, packed_time.as_secs_f64() * 1e6,
+            100.0 * (1.0 - packed_bytes as f64 / full_bytes.max(1) as f64));
+    }
+    Ok(())
+}
+
+///model as one dense volume, order X -> Y -> Z
+fn model_volume(model: &dot_vox::Model) -> (Vec<VoxelID>, [u32; 3]) {
+    let size = [model.size.x, model.size.y, model.size.z];
+    let mut voxels = vec![VoxelID::EMPTY; (size[0] * size[1] * size[2]) as usize];
+    for voxel in &model.voxels {
+        voxels[(voxel.x as u32 + voxel.y as u32 * size[0] + voxel.z as u32 * size[0] * size[1]) as usize] = VoxelID::from_palette_index(voxel.i);
+    }
+    (voxels, size)
+}
+
+///prints time of meshing every model whole with ogt simple: through ogt::Mesh into Vec (what upload_vertices needs)
+/// against streaming into preallocated memory (what upload_streamed writes into mapped staging buffers)
+pub fn streaming(scene: &str) -> Result<(), VoxLoadError> {
+    let scene = load_vox(scene)?;
+    println!("{:<6} {:>16} {:>10} {:>12} {:>12}", "model", "size", "vertices", "mesh us", "stream us");
+    let ogt_palette = [ogt_mesh_rgba {r:4,g:3,b:2,a:1}; 256];
+    for (i, model) in scene.models.iter().enumerate() {
+        let (voxels, size) = model_volume(model);
+        let (vertex_count, index_count) = mesher::stream_capacity(&voxels, size).unwrap();
+
+        let start = Instant::now();
+        for _ in 0..PASSES {
+            let ctx = ogt::Context::new();
+            let mesh = ctx.mesh_simple(mesher::voxels_as_bytes(&voxels), size, &ogt_palette).unwrap();
+            let vertices: Vec<MyVertex> = mesh.vertices().iter().map(|vertex| MyVertex {
+                position: [vertex.pos.x, vertex.pos.y, vertex.pos.z],
+                normal: [vertex.normal.x, vertex.normal.y, vertex.normal.z],
+                mat: vertex.palette_index as u8,
+                ao: 0,
+            }).collect();
+            black_box((vertices, mesh.indices().to_vec()));
+        }
+        let mesh_time = start.elapsed() / PASSES;
+
+        //stands in for mapped staging buffers, allocated once like they would be
+        let mut vertices = vec![MyVertex::default(); vertex_count];
+        let mut indices = vec![0; index_count];
+        let start = Instant::now();
+        for _ in 0..PASSES {
+            mesher::stream_simple(&voxels, size, black_box(&mut vertices), black_box(&mut indices)).unwrap();
+        }
+        let stream_time = start.elapsed() / PASSES;
+
+        println!("{:<6} {:>16} {:>10} {:>12.1} {:>12.1}", i, format!("{}x{}x{}", size[0], size[1], size[2]), vertex_count,
+            mesh_time.as_secs_f64() * 1e6, stream_time.as_secs_f64() * 1e6);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packing_is_lossless_for_every_mesher() {
+        let scene = load_vox("assets/scene.vox").unwrap();
+        //smoothed normals are snapped to closest axis, that is all packed format can hold
+        for mesher in Mesher::ALL.iter().copied().filter(|mesher| *mesher != Mesher::OgtSimpleSmooth) {
+            let mut world = World::new();
+            world.mesher = mesher;
+            world.load_map(&scene).unwrap();
+            for chunk in world.chunks.values() {
+                for vertex in &chunk.mesh.vertices {
+                    let unpacked = PackedVertex::pack(vertex).unpack();
+                    assert_eq!((unpacked.position, unpacked.normal, unpacked.mat, unpacked.ao), (vertex.position, vertex.normal, vertex.mat, vertex.ao), "{:?}", mesher);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn streamed_models_match_ogt_mesh() {
+        let scene = load_vox("assets/scene.vox").unwrap();
+        let ogt_palette = [ogt_mesh_rgba {r:4,g:3,b:2,a:1}; 256];
+        for model in &scene.models {
+            let (voxels, size) = model_volume(model);
+            let (vertex_count, index_count) = mesher::stream_capacity(&voxels, size).unwrap();
+            let mut vertices = vec![MyVertex::default(); vertex_count];
+            let mut indices = vec![0; index_count];
+            mesher::stream_simple(&voxels, size, &mut vertices, &mut indices).unwrap();
+
+            let ctx = ogt::Context::new();
+            let mesh = ctx.mesh_simple(mesher::voxels_as_bytes(&voxels), size, &ogt_palette).unwrap();
+            assert_eq!(indices, mesh.indices());
+            assert!(vertices.iter().zip(mesh.vertices()).all(|(a, b)| a.position == [b.pos.x, b.pos.y, b.pos.z] && a.mat as u32 == b.palette_index));
+        }
+    }
+
+    #[test]
+    fn packed_vertex_is_smaller() {
+        assert_eq!(VertexFormat::Packed.vertex_size(), 5);
+        assert!(VertexFormat::Full.vertex_size() >= 25);
+    }
+}
@@ -0,0 +1,125 @@
+use glam::{Mat4, Vec2, Vec3};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Projection {
+    ///fov_y in radians
+    Perspective { fov_y: f32, near: f32, far: f32 },
+    ///height is how many world units are visible vertically. Isometric is orthographic with diagonal direction
+    Orthographic { height: f32, near: f32, far: f32 },
+}
+
+///world is Z up (same as .vox)
+#[derive(Clone, Copy, Debug)]
+pub struct Camera {
+    pub position: Vec3,
+    ///normalized
+    pub direction: Vec3,
+    pub up: Vec3,
+    pub projection: Projection,
+    ///width / height of target image
+    pub aspect: f32,
+}
+
+impl Camera {
+    pub fn perspective(position: Vec3, target: Vec3, aspect: f32) -> Camera {
+        Camera {
+            position,
+            direction: (target - position).normalize(),
+            up: Vec3::Z,
+            projection: Projection::Perspective { fov_y: 60f32.to_radians(), near: 0.1, far: 2048.0 },
+            aspect,
+        }
+    }
+
+    ///classic isometric view of target, height world units fit vertically
+    pub fn isometric(target: Vec3, height: f32, aspect: f32) -> Camera {
+        let direction = Vec3::new(-1.0, -1.0, -1.0).normalize();
+        //far enough back so nothing in view gets clipped by near plane
+        let distance = height * 4.0;
+        Camera {
+            position: target - direction * distance,
+            direction,
+            up: Vec3::Z,
+            projection: Projection::Orthographic { height, near: 0.0, far: distance * 2.0 },
+            aspect,
+        }
+    }
+
+    pub fn set_extent(&mut self, extent: [u32; 2]) {
+        self.aspect = extent[0] as f32 / extent[1].max(1) as f32;
+    }
+
+    pub fn right(&self) -> Vec3 {
+        self.direction.cross(self.up).normalize()
+    }
+
+    pub fn view(&self) -> Mat4 {
+        Mat4::look_to_rh(self.position, self.direction, self.up)
+    }
+
+    ///depth is 0..1 and Y points down in clip space, as Vulkan wants
+    pub fn proj(&self) -> Mat4 {
+        let proj = match self.projection {
+            Projection::Perspective { fov_y, near, far } => Mat4::perspective_rh(fov_y, self.aspect, near, far),
+            Projection::Orthographic { height, near, far } => {
+                let half_h = height / 2.0;
+                let half_w = half_h * self.aspect;
+                Mat4::orthographic_rh(-half_w, half_w, -half_h, half_h, near, far)
+            }
+        };
+        Mat4::from_scale(Vec3::new(1.0, -1.0, 1.0)) * proj
+    }
+
+    pub fn view_proj(&self) -> Mat4 {
+        self.proj() * self.view()
+    }
+
+    ///world space origin and normalized direction of ray through ndc point (-1..1, y down), starting at near plane
+    pub fn ray(&self, ndc: Vec2) -> (Vec3, Vec3) {
+        let inv = self.view_proj().inverse();
+        let near = inv.project_point3(ndc.extend(0.0));
+        let far = inv.project_point3(ndc.extend(1.0));
+        (near, (far - near).normalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ndc(camera: &Camera, point: Vec3) -> Vec3 {
+        camera.view_proj().project_point3(point)
+    }
+
+    fn assert_near(a: Vec3, b: Vec3) {
+        assert!(a.distance(b) < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn perspective_maps_frustum_edges() {
+        let camera = Camera::perspective(Vec3::new(10.0, 0.0, 0.0), Vec3::ZERO, 2.0);
+        let target = ndc(&camera, Vec3::ZERO);
+        assert_near(target * Vec3::new(1.0, 1.0, 0.0), Vec3::ZERO);
+        assert!(target.z > 0.0 && target.z < 1.0);
+        //fov_y is 60 degrees, so at distance 10 top edge is 10*tan(30) above view axis. Y points down
+        let half_h = 10.0 * 30f32.to_radians().tan();
+        assert_near(ndc(&camera, Vec3::new(0.0, 0.0, half_h)) * Vec3::new(1.0, 1.0, 0.0), Vec3::new(0.0, -1.0, 0.0));
+        assert_near(ndc(&camera, camera.right() * half_h * 2.0) * Vec3::new(1.0, 1.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        //farther point at same offset is closer to center
+        assert!(ndc(&camera, Vec3::new(-10.0, 0.0, half_h)).y > -1.0);
+    }
+
+    #[test]
+    fn isometric_maps_height_to_screen() {
+        let target = Vec3::new(5.0, -3.0, 2.0);
+        let camera = Camera::isometric(target, 40.0, 1.5);
+        assert_near(ndc(&camera, target), Vec3::new(0.0, 0.0, 0.5));
+        let view_up = camera.right().cross(camera.direction);
+        assert_near(ndc(&camera, target + view_up * 20.0), Vec3::new(0.0, -1.0, 0.5));
+        assert_near(ndc(&camera, target + camera.right() * 30.0), Vec3::new(1.0, 0.0, 0.5));
+        //orthographic, so moving along view direction changes only depth
+        let behind = ndc(&camera, target + camera.right() * 30.0 + camera.direction * 10.0);
+        assert_near(behind * Vec3::new(1.0, 1.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        assert!(behind.z > 0.5);
+    }
+}
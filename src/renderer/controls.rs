@@ -0,0 +1,246 @@
+// camera controllers. Driven either by winit events or by filling ControlInput directly (scripts, tools)
+
+use std::collections::HashSet;
+use std::f32::consts::FRAC_PI_2;
+
+use glam::{Vec2, Vec3};
+use winit::event::{DeviceEvent, ElementState, MouseButton, MouseScrollDelta, VirtualKeyCode, WindowEvent};
+
+use crate::camera::{Camera, Projection};
+use crate::world::VoxelEdit;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ControlMode {
+    ///WASD moves along view, Space/LShift up/down, right mouse drag looks around
+    Fly,
+    ///left mouse drag rotates around target, scroll zooms, WASD pans target
+    Orbit,
+}
+
+///what camera should do on next update, no matter where it came from
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ControlInput {
+    ///-1..1 along camera right, forward and world up. Kept between updates
+    pub movement: Vec3,
+    ///mouse delta in pixels (x right, y down), consumed by update
+    pub look: Vec2,
+    ///scroll in lines, positive zooms in, consumed by update
+    pub zoom: f32,
+    ///switch between Fly and Orbit, consumed by update
+    pub toggle_mode: bool,
+    ///edit of voxel in the middle of view (Q removes, E places), consumed by take_edit
+    pub edit: Option<VoxelEdit>,
+}
+
+pub struct CameraController {
+    pub mode: ControlMode,
+    ///what Orbit rotates around
+    pub target: Vec3,
+    ///world units per second
+    pub speed: f32,
+    ///radians per pixel of mouse movement
+    pub sensitivity: f32,
+    ///how much one scroll line scales orbit distance (or ortho height)
+    pub zoom_step: f32,
+    pub input: ControlInput,
+    //held keys, so movement does not depend on key repeat rate
+    held: HashSet<VirtualKeyCode>,
+    looking: bool,
+}
+
+const MAX_PITCH: f32 = FRAC_PI_2 - 0.01;
+
+impl CameraController {
+    pub fn new(mode: ControlMode, target: Vec3) -> Self {
+        CameraController {
+            mode,
+            target,
+            speed: 64.0,
+            sensitivity: 0.005,
+            zoom_step: 0.9,
+            input: ControlInput::default(),
+            held: HashSet::new(),
+            looking: false,
+        }
+    }
+
+    ///point camera is looking at: orbit target, or camera itself when flying
+    pub fn focus(&self, camera: &Camera) -> Vec3 {
+        match self.mode {
+            ControlMode::Fly => camera.position,
+            ControlMode::Orbit => self.target,
+        }
+    }
+
+    ///returns true if event was used
+    pub fn handle_window_event(&mut self, event: &WindowEvent) -> bool {
+        match event {
+            WindowEvent::KeyboardInput { input, .. } => {
+                let Some(key) = input.virtual_keycode else { return false };
+                match input.state {
+                    ElementState::Pressed => {
+                        //key repeat must not toggle or edit again
+                        if !self.held.contains(&key) {
+                            match key {
+                                VirtualKeyCode::Tab => self.input.toggle_mode = true,
+                                VirtualKeyCode::Q => self.input.edit = Some(VoxelEdit::Remove),
+                                VirtualKeyCode::E => self.input.edit = Some(VoxelEdit::Place),
+                                _ => {}
+                            }
+                        }
+                        self.held.insert(key);
+                    }
+                    ElementState::Released => {
+                        self.held.remove(&key);
+                    }
+                }
+                true
+            }
+            WindowEvent::MouseInput { state, button, .. } => {
+                let look_button = match self.mode {
+                    ControlMode::Fly => MouseButton::Right,
+                    ControlMode::Orbit => MouseButton::Left,
+                };
+                if *button != look_button {
+                    return false;
+                }
+                self.looking = *state == ElementState::Pressed;
+                true
+            }
+            WindowEvent::MouseWheel { delta, .. } => {
+                self.input.zoom += match delta {
+                    MouseScrollDelta::LineDelta(_, y) => *y,
+                    //roughly one line
+                    MouseScrollDelta::PixelDelta(p) => p.y as f32 / 20.0,
+                };
+                true
+            }
+            //keys released while unfocused never come back
+            WindowEvent::Focused(false) => {
+                self.held.clear();
+                self.looking = false;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    ///edit asked for since last call, camera does nothing with it. See World::edit_at
+    pub fn take_edit(&mut self) -> Option<VoxelEdit> {
+        self.input.edit.take()
+    }
+
+    ///raw mouse motion, unlike CursorMoved it keeps coming at window edges
+    pub fn handle_device_event(&mut self, event: &DeviceEvent) {
+        if let DeviceEvent::MouseMotion { delta } = event {
+            if self.looking {
+                self.input.look += Vec2::new(delta.0 as f32, delta.1 as f32);
+            }
+        }
+    }
+
+    fn held_movement(&self) -> Vec3 {
+        let axis = |pos: VirtualKeyCode, neg: VirtualKeyCode| {
+            self.held.contains(&pos) as i32 as f32 - self.held.contains(&neg) as i32 as f32
+        };
+        Vec3::new(
+            axis(VirtualKeyCode::D, VirtualKeyCode::A),
+            axis(VirtualKeyCode::W, VirtualKeyCode::S),
+            axis(VirtualKeyCode::Space, VirtualKeyCode::LShift),
+        )
+    }
+
+    ///moves camera by dt seconds of input. Returns true if camera changed (so command buffers need new view_proj)
+    pub fn update(&mut self, camera: &mut Camera, dt: f32) -> bool {
+        let before = (camera.position, camera.direction, camera.projection);
+
+        if self.input.toggle_mode {
+            self.input.toggle_mode = false;
+            self.mode = match self.mode {
+                ControlMode::Fly => {
+                    //orbit around whatever was in front, at the same distance as before
+                    self.target = camera.position + camera.direction * (self.target - camera.position).length();
+                    ControlMode::Orbit
+                }
+                ControlMode::Orbit => ControlMode::Fly,
+            };
+            self.looking = false;
+        }
+
+        let looked = self.input.look != Vec2::ZERO;
+        if looked {
+            //yaw/pitch come from camera itself, so anyone else may turn it between updates
+            let direction = camera.direction;
+            let yaw = direction.y.atan2(direction.x) - self.input.look.x * self.sensitivity;
+            let pitch = (direction.z.clamp(-1.0, 1.0).asin() - self.input.look.y * self.sensitivity).clamp(-MAX_PITCH, MAX_PITCH);
+            camera.direction = Vec3::new(pitch.cos() * yaw.cos(), pitch.cos() * yaw.sin(), pitch.sin());
+            self.input.look = Vec2::ZERO;
+        }
+
+        let movement = (self.input.movement + self.held_movement()).clamp(Vec3::splat(-1.0), Vec3::splat(1.0));
+        let right = camera.right();
+        let step = self.speed * dt;
+
+        match self.mode {
+            ControlMode::Fly => {
+                camera.position += (right * movement.x + camera.direction * movement.y + Vec3::Z * movement.z) * step;
+            }
+            //recomputing position from target every frame would drift it, so only when something happened
+            ControlMode::Orbit if looked || movement != Vec3::ZERO || self.input.zoom != 0.0 => {
+                let mut distance = (camera.position - self.target).length();
+                //pan along ground, not into it
+                let forward = Vec3::new(camera.direction.x, camera.direction.y, 0.0).normalize_or_zero();
+                self.target += (right * movement.x + forward * movement.y + Vec3::Z * movement.z) * step;
+
+                let zoom = self.zoom_step.powf(self.input.zoom);
+                match &mut camera.projection {
+                    //moving ortho camera closer changes nothing, so shrink view instead
+                    Projection::Orthographic { height, .. } => *height *= zoom,
+                    Projection::Perspective { .. } => distance *= zoom,
+                }
+                camera.position = self.target - camera.direction * distance;
+            }
+            ControlMode::Orbit => {}
+        }
+        self.input.zoom = 0.0;
+
+        before != (camera.position, camera.direction, camera.projection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moved(mode: ControlMode, steps: usize, dt: f32) -> Camera {
+        let mut camera = Camera::perspective(Vec3::new(100.0, 0.0, 0.0), Vec3::ZERO, 1.0);
+        let mut controller = CameraController::new(mode, Vec3::ZERO);
+        controller.input.movement = Vec3::new(0.5, 1.0, 0.0);
+        for _ in 0..steps {
+            controller.update(&mut camera, dt);
+        }
+        camera
+    }
+
+    #[test]
+    fn movement_does_not_depend_on_frame_rate() {
+        for mode in [ControlMode::Fly, ControlMode::Orbit] {
+            let slow = moved(mode, 10, 0.1);
+            let fast = moved(mode, 100, 0.01);
+            assert!(slow.position.distance(fast.position) < 1e-3, "{:?}: {} vs {}", mode, slow.position, fast.position);
+        }
+    }
+
+    #[test]
+    fn orbit_keeps_distance_to_target() {
+        let mut camera = Camera::perspective(Vec3::new(100.0, 0.0, 50.0), Vec3::ZERO, 1.0);
+        let mut controller = CameraController::new(ControlMode::Orbit, Vec3::ZERO);
+        let distance = camera.position.length();
+        controller.input.look = Vec2::new(300.0, -120.0);
+        assert!(controller.update(&mut camera, 0.016));
+        assert!((camera.position.length() - distance).abs() < 1e-3);
+        assert!(camera.direction.dot(-camera.position.normalize()) > 0.999);
+        //nothing to do, nothing changes
+        assert!(!controller.update(&mut camera, 0.016));
+    }
+}
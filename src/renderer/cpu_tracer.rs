@@ -0,0 +1,210 @@
+// pure CPU rendering of World: no Vulkan needed, so it works in CI and for offline renders
+// render_direct shades exactly like trace.comp (ground truth for it), render_path_traced is slow but pretty
+
+use std::thread;
+
+use glam::{IVec3, Vec2, Vec3};
+use image::Rgba32FImage;
+
+use crate::camera::Camera;
+use crate::tracer::{trace, Hit, Ray, Volume};
+use crate::world::{BlockID, VoxelID, World, BLOCK_SIZE};
+
+///direction towards sun, same as SUN in voxel.glsl (which is normalized)
+pub const SUN: Vec3 = Vec3::new(0.4, 0.3, 1.0);
+///same as SKY in voxel.glsl
+pub const SKY: Vec3 = Vec3::new(0.55, 0.7, 0.9);
+const FAR: f32 = 1e30;
+
+///walks loaded chunks directly, no flattening like VoxelVolume
+impl Volume for World {
+    fn block_bounds(&self) -> (IVec3, IVec3) {
+        self.loaded_bounds().unwrap_or((IVec3::ZERO, IVec3::ZERO))
+    }
+
+    fn block(&self, pos: IVec3) -> BlockID {
+        self.loaded_block(pos)
+    }
+
+    fn voxel(&self, block: BlockID, local: IVec3) -> VoxelID {
+        let voxels = self.get_block_from_palette(block);
+        voxels[local.x as usize + local.y as usize*BLOCK_SIZE + local.z as usize*BLOCK_SIZE*BLOCK_SIZE]
+    }
+}
+
+///xorshift32, good enough for sampling and needs no dependency
+struct Rng(u32);
+
+impl Rng {
+    fn new(seed: u32) -> Rng {
+        //scramble so neighbouring seeds give unrelated sequences, and never 0
+        Rng(seed.wrapping_mul(0x9E37_79B9) ^ 0x2545_F491 | 1)
+    }
+
+    ///0..1
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        (self.0 >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    fn unit_vector(&mut self) -> Vec3 {
+        let z = self.next() * 2.0 - 1.0;
+        let angle = self.next() * std::f32::consts::TAU;
+        let r = (1.0 - z*z).max(0.0).sqrt();
+        Vec3::new(r * angle.cos(), r * angle.sin(), z)
+    }
+}
+
+///normal of face ray entered hit through (or facing ray if it started inside) and point just outside of it
+fn surface(ray: &Ray, hit: &Hit) -> (Vec3, Vec3) {
+    let normal = if hit.normal == IVec3::ZERO {-ray.direction} else {hit.normal.as_vec3()};
+    (normal, ray.at(hit.t) + normal * 1e-3)
+}
+
+fn in_sun(world: &World, point: Vec3) -> bool {
+    trace(world, &Ray::new(point, SUN), FAR).is_none()
+}
+
+///ambient + sun with hard shadows + emission, same as shade_surface() in voxel.glsl
+fn shade_direct(world: &World, ray: &Ray) -> Vec3 {
+    let Some(hit) = trace(world, ray, FAR) else { return SKY };
+    let material = &world.voxel_palette[hit.id.0 as usize];
+    let color = material.color.truncate();
+    let (normal, point) = surface(ray, &hit);
+    let sun = SUN.normalize();
+    let diffuse = if in_sun(world, point) {normal.dot(sun).max(0.0)} else {0.0};
+    color * (0.25 * SKY + 0.75 * diffuse) + color * material.emmitance
+}
+
+///one path: diffuse and metal bounces, emission, sun sampled at every hit. Glass is treated as diffuse
+fn radiance(world: &World, mut ray: Ray, bounces: u32, rng: &mut Rng) -> Vec3 {
+    let sun = SUN.normalize();
+    let mut throughput = Vec3::ONE;
+    let mut light = Vec3::ZERO;
+    for _ in 0..=bounces {
+        let Some(hit) = trace(world, &ray, FAR) else {
+            //brighter towards zenith
+            light += throughput * SKY * (0.6 + 0.4 * ray.direction.z.max(0.0));
+            break;
+        };
+        let material = &world.voxel_palette[hit.id.0 as usize];
+        let color = material.color.truncate();
+        let (normal, point) = surface(&ray, &hit);
+
+        light += throughput * color * material.emmitance;
+        if in_sun(world, point) {
+            light += throughput * color * normal.dot(sun).max(0.0);
+        }
+
+        let direction = if rng.next() < material.metalness {
+            let reflected = ray.direction - 2.0 * ray.direction.dot(normal) * normal;
+            reflected + rng.unit_vector() * material.roughness
+        } else {
+            //cosine weighted hemisphere
+            normal + rng.unit_vector()
+        };
+        if direction.dot(normal) <= 0.0 {
+            break;
+        }
+        throughput *= color;
+        ray = Ray::new(point, direction);
+    }
+    light
+}
+
+///calls pixel for every pixel on all cores. Rows top to bottom, same as HeadlessRenderer
+fn render_with(extent: [u32; 2], pixel: impl Fn(u32, u32) -> Vec3 + Sync) -> Rgba32FImage {
+    let mut image = Rgba32FImage::new(extent[0], extent[1]);
+    let row_len = extent[0] as usize * 4;
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let rows_per_thread = (extent[1] as usize).div_ceil(threads).max(1);
+
+    let pixel = &pixel;
+    thread::scope(|scope| {
+        for (part, rows) in image.chunks_mut(row_len * rows_per_thread).enumerate() {
+            scope.spawn(move || {
+                for (i, rgba) in rows.chunks_mut(4).enumerate() {
+                    let x = (i % extent[0] as usize) as u32;
+                    let y = (part * rows_per_thread + i / extent[0] as usize) as u32;
+                    let color = pixel(x, y);
+                    rgba.copy_from_slice(&[color.x, color.y, color.z, 1.0]);
+                }
+            });
+        }
+    });
+    image
+}
+
+///pixel (x, y) + offset (0..1 inside of pixel) in ndc
+fn pixel_ndc(extent: [u32; 2], x: u32, y: u32, offset: Vec2) -> Vec2 {
+    (Vec2::new(x as f32, y as f32) + offset) / Vec2::new(extent[0] as f32, extent[1] as f32) * 2.0 - 1.0
+}
+
+///one ray per pixel center, shaded like trace.comp
+pub fn render_direct(world: &World, camera: &Camera, extent: [u32; 2]) -> Rgba32FImage {
+    render_with(extent, |x, y| {
+        let (origin, direction) = camera.ray(pixel_ndc(extent, x, y, Vec2::splat(0.5)));
+        shade_direct(world, &Ray::new(origin, direction))
+    })
+}
+
+///samples jittered paths per pixel, each up to bounces long. Same seed gives same image
+pub fn render_path_traced(world: &World, camera: &Camera, extent: [u32; 2], samples: u32, bounces: u32) -> Rgba32FImage {
+    render_with(extent, |x, y| {
+        let mut rng = Rng::new(x + y * extent[0]);
+        let mut sum = Vec3::ZERO;
+        for _ in 0..samples {
+            let offset = Vec2::new(rng.next(), rng.next());
+            let (origin, direction) = camera.ray(pixel_ndc(extent, x, y, offset));
+            sum += radiance(world, Ray::new(origin, direction), bounces, &mut rng);
+        }
+        sum / samples.max(1) as f32
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loader::load_vox;
+    use crate::tracer::VoxelVolume;
+
+    fn setup() -> (World, Camera) {
+        let mut world = World::new();
+        world.load_map(&load_vox("assets/scene.vox").unwrap()).unwrap();
+        let (min, max) = world.mesh_bounds().unwrap();
+        let camera = Camera::isometric((min + max) / 2.0, (max - min).length(), 1.0);
+        (world, camera)
+    }
+
+    #[test]
+    fn world_and_volume_trace_the_same() {
+        let (world, camera) = setup();
+        let volume = VoxelVolume::new(&world);
+        for y in 0..16 {
+        for x in 0..16 {
+            let (origin, direction) = camera.ray(pixel_ndc([16, 16], x, y, Vec2::splat(0.5)));
+            let ray = Ray::new(origin, direction);
+            assert_eq!(trace(&world, &ray, FAR), trace(&volume, &ray, FAR));
+        }
+        }
+    }
+
+    #[test]
+    fn direct_render_sees_scene() {
+        let (world, camera) = setup();
+        let image = render_direct(&world, &camera, [32, 32]);
+        let not_sky = image.pixels().filter(|p| Vec3::new(p[0], p[1], p[2]) != SKY).count();
+        assert!(not_sky > 32, "only {} pixels hit something", not_sky);
+    }
+
+    #[test]
+    fn path_tracing_is_deterministic() {
+        let (world, camera) = setup();
+        let a = render_path_traced(&world, &camera, [8, 8], 2, 2);
+        let b = render_path_traced(&world, &camera, [8, 8], 2, 2);
+        assert_eq!(a.as_raw(), b.as_raw());
+        assert!(a.as_raw().iter().all(|c| c.is_finite() && *c >= 0.0));
+    }
+}
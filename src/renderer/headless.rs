@@ -0,0 +1,232 @@
+// renders World into offscreen image instead of swapchain, so it works without window (CI with software Vulkan, thumbnails)
+
+use std::{error::Error, path::Path, sync::Arc};
+
+use vulkano::buffer::{Buffer, BufferCreateInfo, BufferUsage, Subbuffer};
+use vulkano::command_buffer::allocator::StandardCommandBufferAllocator;
+use vulkano::command_buffer::{AutoCommandBufferBuilder, CommandBufferUsage, CopyImageToBufferInfo, PrimaryAutoCommandBuffer, PrimaryCommandBufferAbstract};
+use vulkano::descriptor_set::allocator::StandardDescriptorSetAllocator;
+use vulkano::device::physical::{PhysicalDevice, PhysicalDeviceType};
+use vulkano::device::{Device, DeviceExtensions, Queue, QueueFlags};
+use vulkano::format::Format;
+use vulkano::image::{Image, ImageCreateInfo, ImageType, ImageUsage};
+use vulkano::instance::{Instance, InstanceExtensions};
+use vulkano::memory::allocator::{AllocationCreateInfo, MemoryTypeFilter, StandardMemoryAllocator};
+use vulkano::pipeline::graphics::viewport::Viewport;
+use vulkano::sync::GpuFuture;
+
+use crate::camera::Camera;
+use crate::tracer::{get_trace_command_buffer, get_trace_pipeline, upload_volume, VolumeError};
+use crate::world::World;
+use crate::{create_device, create_instance_with_extensions, get_command_buffers, DrawTargets, get_framebuffers, get_graphical_pipeline, get_lighting_pipeline, get_render_pass, upload_world, VertexFormat};
+use crate::loader::load_shader;
+
+///float so EXR keeps full range, PNG gets clamped
+const FRAME_FORMAT: Format = Format::R32G32B32A32_SFLOAT;
+
+pub struct HeadlessRenderer {
+    pub device: Arc<Device>,
+    pub queue: Arc<Queue>,
+    ///what render uploads chunk meshes as
+    pub vertex_format: VertexFormat,
+    memory_allocator: Arc<StandardMemoryAllocator>,
+    command_buffer_allocator: StandardCommandBufferAllocator,
+    descriptor_set_allocator: StandardDescriptorSetAllocator,
+    _instance: Arc<Instance>,
+}
+
+///same as select_physical_device, but without surface support check
+pub fn select_headless_physical_device(instance: &Arc<Instance>, device_extensions: &DeviceExtensions) -> (Arc<PhysicalDevice>, u32) {
+    instance
+        .enumerate_physical_devices()
+        .expect("failed to enumerate physical devices")
+        .filter(|p| p.supported_extensions().contains(device_extensions))
+        .filter_map(|p| {
+            p.queue_family_properties()
+                .iter()
+                .position(|q| q.queue_flags.contains(QueueFlags::GRAPHICS) && q.queue_flags.contains(QueueFlags::COMPUTE))
+                .map(|q| (p, q as u32))
+        })
+        .min_by_key(|(p, _)| match p.properties().device_type {
+            PhysicalDeviceType::DiscreteGpu => 0,
+            PhysicalDeviceType::IntegratedGpu => 1,
+            PhysicalDeviceType::VirtualGpu => 2,
+            PhysicalDeviceType::Cpu => 3,
+            _ => 4,
+        }).expect("no device available")
+}
+
+impl HeadlessRenderer {
+    pub fn new() -> Self {
+        let instance = create_instance_with_extensions(InstanceExtensions::empty());
+        let device_extensions = DeviceExtensions::empty();
+        let (physical_device, queue_family_index) = select_headless_physical_device(&instance, &device_extensions);
+        let (device, mut queues) = create_device(physical_device, queue_family_index, device_extensions);
+        let queue = queues.next().unwrap();
+
+        HeadlessRenderer {
+            memory_allocator: Arc::new(StandardMemoryAllocator::new_default(device.clone())),
+            command_buffer_allocator: StandardCommandBufferAllocator::new(device.clone(), Default::default()),
+            descriptor_set_allocator: StandardDescriptorSetAllocator::new(device.clone(), Default::default()),
+            device,
+            queue,
+            vertex_format: VertexFormat::Full,
+            _instance: instance,
+        }
+    }
+
+    ///draws every loaded chunk of world into G-buffer, lights it and reads frame back. Result is extent[0]*extent[1] rgba, rows top to bottom
+    /// fails if world does not fit into block atlas of this device
+    pub fn render(&self, world: &World, camera: &Camera, extent: [u32; 2]) -> Result<Vec<f32>, VolumeError> {
+        let image = Image::new(self.memory_allocator.clone(), ImageCreateInfo {
+            image_type: ImageType::Dim2d,
+            format: FRAME_FORMAT,
+            extent: [extent[0], extent[1], 1],
+            usage: ImageUsage::COLOR_ATTACHMENT | ImageUsage::TRANSFER_SRC,
+            ..Default::default()
+        }, AllocationCreateInfo {
+            ..Default::default()
+        }).unwrap();
+
+        let render_pass = get_render_pass(self.device.clone(), FRAME_FORMAT);
+        let framebuffers = get_framebuffers(&[image.clone()], render_pass.clone(), self.memory_allocator.clone());
+
+        let vs = load_shader(self.device.clone(), self.vertex_format.vertex_shader());
+        let fs = load_shader(self.device.clone(), "shaders/frag.spv");
+        let viewport = Viewport {
+            offset: [0.0, 0.0],
+            extent: [extent[0] as f32, extent[1] as f32],
+            depth_range: 0.0..=1.0,
+        };
+        let light_vs = load_shader(self.device.clone(), "shaders/light_vert.spv");
+        let light_fs = load_shader(self.device.clone(), "shaders/light_frag.spv");
+        let pipeline = get_graphical_pipeline(self.device.clone(), vs, fs, render_pass.clone(), viewport.clone(), self.vertex_format);
+        let lighting_pipeline = get_lighting_pipeline(self.device.clone(), light_vs, light_fs, render_pass, viewport);
+
+        let chunk_buffers = upload_world(self.memory_allocator.clone(), &self.command_buffer_allocator, &self.queue, world, self.vertex_format);
+        let volume = upload_volume(self.memory_allocator.clone(), &self.command_buffer_allocator, &self.queue, world)?;
+
+        let draw = get_command_buffers(
+            &self.command_buffer_allocator,
+            &self.descriptor_set_allocator,
+            &self.queue,
+            &DrawTargets {pipeline: &pipeline, lighting_pipeline: &lighting_pipeline, framebuffers: &framebuffers, volume: &volume},
+            &chunk_buffers,
+            camera,
+        ).remove(0);
+
+        Ok(self.read_back(draw, image, extent))
+    }
+
+    ///ray traces loaded chunks of world with trace.comp instead of rasterizing meshes
+    pub fn trace(&self, world: &World, camera: &Camera, extent: [u32; 2]) -> Result<Vec<f32>, VolumeError> {
+        let image = Image::new(self.memory_allocator.clone(), ImageCreateInfo {
+            image_type: ImageType::Dim2d,
+            format: FRAME_FORMAT,
+            extent: [extent[0], extent[1], 1],
+            usage: ImageUsage::STORAGE | ImageUsage::TRANSFER_SRC,
+            ..Default::default()
+        }, AllocationCreateInfo {
+            ..Default::default()
+        }).unwrap();
+
+        let cs = load_shader(self.device.clone(), "shaders/trace.spv");
+        let pipeline = get_trace_pipeline(self.device.clone(), cs);
+        let volume = upload_volume(self.memory_allocator.clone(), &self.command_buffer_allocator, &self.queue, world)?;
+
+        let trace = get_trace_command_buffer(
+            &self.command_buffer_allocator,
+            &self.descriptor_set_allocator,
+            &self.queue,
+            &pipeline,
+            &volume,
+            image.clone(),
+            camera,
+        );
+        Ok(self.read_back(trace, image, extent))
+    }
+
+    ///runs commands that fill image, then copies it to host
+    fn read_back(&self, commands: Arc<PrimaryAutoCommandBuffer>, image: Arc<Image>, extent: [u32; 2]) -> Vec<f32> {
+        let readback: Subbuffer<[f32]> = Buffer::new_slice(
+            self.memory_allocator.clone(),
+            BufferCreateInfo {
+                usage: BufferUsage::TRANSFER_DST,
+                ..Default::default()
+            },
+            AllocationCreateInfo {
+                memory_type_filter: MemoryTypeFilter::PREFER_HOST
+                    | MemoryTypeFilter::HOST_RANDOM_ACCESS,
+                ..Default::default()
+            },
+            (extent[0] * extent[1] * 4) as u64,
+        ).unwrap();
+
+        let mut cbb = AutoCommandBufferBuilder::primary(
+            &self.command_buffer_allocator,
+            self.queue.queue_family_index(),
+            CommandBufferUsage::OneTimeSubmit,
+        )
+        .unwrap();
+        cbb.copy_image_to_buffer(CopyImageToBufferInfo::image_buffer(image, readback.clone()))
+            .unwrap();
+        let copy = cbb.build().unwrap();
+
+        commands.execute(self.queue.clone())
+            .unwrap()
+            .then_execute(self.queue.clone(), copy)
+            .unwrap()
+            .then_signal_fence_and_flush()
+            .unwrap()
+            .wait(None /* timeout */)
+            .unwrap();
+
+        let pixels = readback.read().unwrap();
+        pixels.to_vec()
+    }
+}
+
+///writes rgba frame (rows top to bottom) as .exr (as is) or anything else image supports, e.g. .png (clamped to 0..1)
+pub fn save_frame(path: &Path, extent: [u32; 2], pixels: &[f32]) -> Result<(), Box<dyn Error>> {
+    let is_exr = path.extension().map_or(false, |ext| ext.eq_ignore_ascii_case("exr"));
+    if is_exr {
+        let width = extent[0] as usize;
+        exr::prelude::write_rgba_file(path, width, extent[1] as usize, |x, y| {
+            let p = &pixels[(x + y*width)*4..][..4];
+            (p[0], p[1], p[2], p[3])
+        })?;
+    } else {
+        let bytes: Vec<u8> = pixels.iter().map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8).collect();
+        image::save_buffer(path, &bytes, extent[0], extent[1], image::ColorType::Rgba8)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_frame_reads_back() {
+        let extent = [3, 2];
+        //multiples of 1/255 survive PNG as they are, 2.0 only survives EXR
+        let pixels: Vec<f32> = (0..3 * 2 * 4).map(|i| if i == 5 { 2.0 } else { (i * 10) as f32 / 255.0 }).collect();
+        let dir = std::env::temp_dir().join(format!("vk-rs-save-frame-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let png = dir.join("frame.png");
+        save_frame(&png, extent, &pixels).unwrap();
+        let image = image::open(&png).unwrap().into_rgba8();
+        assert_eq!(image.dimensions(), (3, 2));
+        let expected: Vec<u8> = pixels.iter().map(|c| (c.min(1.0) * 255.0).round() as u8).collect();
+        assert_eq!(image.into_raw(), expected);
+
+        let exr = dir.join("frame.exr");
+        save_frame(&exr, extent, &pixels).unwrap();
+        let image = image::open(&exr).unwrap().into_rgba32f();
+        assert_eq!(image.dimensions(), (3, 2));
+        assert_eq!(image.into_raw(), pixels);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,177 @@
+// background meshing: World hands padded blocks to worker threads and picks finished meshes up once per frame
+// every worker keeps ogt context of its own for its whole life,
+// allocating from an arena that is emptied after every block instead of going to the heap.
+// Block the arena is too small for is meshed again on heap, capped by HEAP_BUDGET
+
+use std::mem::size_of;
+use std::sync::{atomic::{AtomicBool, AtomicUsize, Ordering}, mpsc, Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use glam::IVec3;
+
+use crate::mesher::{BlockMesh, Mesher, PADDED, PADDED_VOLUME};
+use crate::ogt;
+use crate::ogt::{ogt_mesh_vec3, ogt_mesh_vertex};
+use crate::world::VoxelID;
+
+///most ogt needs for one padded block: every voxel face visible (4 vertices, 6 indices each),
+/// two slice tables of every greedy / polygon pass, then remove_duplicate_vertices hash table and remap,
+/// then smooth_normals the same plus normal sums. Plus room for headers
+const ARENA_BYTES: usize = {
+    let faces = PADDED_VOLUME * 6;
+    let mesh = faces * (4 * size_of::<ogt_mesh_vertex>() + 6 * size_of::<u32>());
+    let slices = 6 * 2 * (PADDED*PADDED).next_multiple_of(16);
+    let scratch = (faces * 4).next_power_of_two() * size_of::<u32>() + faces * 4 * size_of::<u32>();
+    let smooth = scratch + faces * 4 * size_of::<ogt_mesh_vec3>();
+    mesh + slices + scratch + smooth + 4096
+};
+///most heap a worker takes for block its arena refused, past that World meshes it on main thread
+const HEAP_BUDGET: usize = 4 * ARENA_BYTES;
+
+///everything needed to mesh one block, World is never touched by workers
+pub struct MeshJob {
+    ///block (in blocks) mesh is for
+    pub block_pos: IVec3,
+    ///increases with every job, result of a job older than the latest one for the same block is stale
+    pub id: u64,
+    pub mesher: Mesher,
+    ///see mesher::padded_index
+    pub padded: Vec<VoxelID>,
+}
+
+///block-local mesh of MeshJob, error if ogt needed more than worker may take
+pub struct MeshDone {
+    pub block_pos: IVec3,
+    pub id: u64,
+    pub mesh: Result<BlockMesh, ogt::MeshError>,
+}
+
+///meshes job in arena of ctx, on budgeted heap if arena refuses. Arena is empty again afterwards
+fn mesh_job(ctx: &mut ogt::Context, job: &MeshJob) -> Result<BlockMesh, ogt::MeshError> {
+    let mesh = job.mesher.mesh_block_with(ctx, &job.padded);
+    ctx.reset_arena();
+    mesh.or_else(|_| job.mesher.mesh_block_with(&ogt::Context::builder().budget(HEAP_BUDGET).build(), &job.padded))
+}
+
+///pool of meshing threads fed through one queue, stopped on drop
+pub struct MeshWorkers {
+    ///None only while dropping, closing it lets workers leave their loop
+    jobs: Option<mpsc::Sender<MeshJob>>,
+    ///behind mutex only so World (and whatever borrows it across threads, like cpu_tracer) stays Sync
+    done: Mutex<mpsc::Receiver<MeshDone>>,
+    ///set on drop, so workers skip whatever is still queued
+    stop: Arc<AtomicBool>,
+    ///most ogt memory any worker had in use at once
+    peak: Arc<AtomicUsize>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl MeshWorkers {
+    pub fn new(threads: usize) -> Self {
+        assert!(threads > 0, "mesh workers need at least one thread");
+        let (jobs, queue) = mpsc::channel::<MeshJob>();
+        let (done_sender, done) = mpsc::channel();
+        //workers take turns on the queue, whoever is idle gets next job
+        let queue = Arc::new(Mutex::new(queue));
+        let stop = Arc::new(AtomicBool::new(false));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let threads = (0..threads).map(|i| {
+            let queue = queue.clone();
+            let done = done_sender.clone();
+            let stop = stop.clone();
+            let peak = peak.clone();
+            thread::Builder::new().name(format!("mesher {}", i)).spawn(move || {
+                let mut ctx = ogt::Context::builder().arena(ARENA_BYTES).build();
+                loop {
+                    //lock is released before meshing, so others can take jobs meanwhile
+                    let job = queue.lock().unwrap().recv();
+                    let Ok(job) = job else {break;};
+                    if stop.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    let mesh = mesh_job(&mut ctx, &job);
+                    peak.fetch_max(ctx.stats().peak, Ordering::Relaxed);
+                    if done.send(MeshDone {block_pos: job.block_pos, id: job.id, mesh}).is_err() {
+                        break;
+                    }
+                }
+            }).expect("failed to spawn meshing thread")
+        }).collect();
+        MeshWorkers {jobs: Some(jobs), done: Mutex::new(done), stop, peak, threads}
+    }
+
+    ///one less than there are cores, so main thread keeps one for itself
+    pub fn default_threads() -> usize {
+        thread::available_parallelism().map_or(1, |n| n.get().saturating_sub(1).max(1))
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads.len()
+    }
+
+    ///most bytes ogt had allocated at once on any worker, see ogt::AllocStats
+    pub fn peak_bytes(&self) -> usize {
+        self.peak.load(Ordering::Relaxed)
+    }
+
+    pub fn submit(&self, job: MeshJob) {
+        self.jobs.as_ref().unwrap().send(job).expect("every meshing thread is gone");
+    }
+
+    ///results finished so far, never blocks
+    pub fn finished(&self) -> Vec<MeshDone> {
+        self.done.lock().unwrap().try_iter().collect()
+    }
+}
+
+impl Drop for MeshWorkers {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        drop(self.jobs.take());
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesher::padded_index;
+
+    fn job(mesher: Mesher) -> MeshJob {
+        let mut padded = vec![VoxelID::EMPTY; PADDED_VOLUME];
+        for i in 0..6 {
+            padded[padded_index(IVec3::new(i, i % 3, 2))] = VoxelID(1 + i as u8);
+        }
+        MeshJob {block_pos: IVec3::ZERO, id: 1, mesher, padded}
+    }
+
+    #[test]
+    fn refused_arena_falls_back_to_heap() {
+        for mesher in [Mesher::OgtSimple, Mesher::OgtGreedy, Mesher::OgtPolygon, Mesher::OgtSimpleSmooth] {
+            let job = job(mesher);
+            let mut tiny = ogt::Context::builder().arena(64).build();
+            assert!(job.mesher.mesh_block_with(&tiny, &job.padded).is_err(), "{:?}", mesher);
+            tiny.reset_arena();
+
+            let mesh = mesh_job(&mut tiny, &job).unwrap();
+            let expected = mesher.mesh_block(&job.padded);
+            assert_eq!(mesh.indices, expected.indices, "{:?}", mesher);
+            assert!(tiny.stats().refused > 0);
+        }
+    }
+
+    #[test]
+    fn arena_fits_checkerboard_block() {
+        //every voxel face visible, most any block can make
+        let padded: Vec<VoxelID> = (0..PADDED_VOLUME).map(|i| VoxelID(((i % PADDED + i / PADDED % PADDED + i / (PADDED*PADDED)) % 2) as u8)).collect();
+        let mut ctx = ogt::Context::builder().arena(ARENA_BYTES).build();
+        for mesher in [Mesher::OgtSimple, Mesher::OgtGreedy, Mesher::OgtPolygon, Mesher::OgtSimpleSmooth] {
+            let mesh = mesher.mesh_block_with(&ctx, &padded).unwrap();
+            assert!(!mesh.indices.is_empty(), "{:?}", mesher);
+            ctx.reset_arena();
+        }
+        assert_eq!(ctx.stats().refused, 0);
+    }
+}
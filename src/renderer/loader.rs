@@ -1,10 +1,11 @@
-use std::{io::{Error, ErrorKind}, sync::Arc};
+use std::{fmt, fs, io::{Error, ErrorKind}, path::Path, sync::Arc};
 use std::{fs::File, io::{BufReader, Read}};
 
 extern crate vulkano;
 extern crate winit;
 extern crate exr;
 
+use dot_vox::DotVoxData;
 use vulkano::device::Device;
 use vulkano::shader::ShaderModule;
 use vulkano::shader::ShaderModuleCreateInfo;
@@ -42,4 +43,157 @@ pub fn load_shader(device: Arc<Device>, name: &str) -> Arc<ShaderModule>{
 unsafe {
     let sm = ShaderModule::new(device, create_info).unwrap();
     return sm;
-}}
\ No newline at end of file
+}}
+
+#[derive(Debug)]
+pub enum VoxLoadError {
+    ///file is missing or unreadable
+    Io(Error),
+    ///not a valid MagicaVoxel .vox
+    Parse(&'static str),
+    ///scene has more distinct blocks than World::max_blocks (empty one included)
+    TooManyBlocks { limit: usize },
+}
+
+impl fmt::Display for VoxLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VoxLoadError::Io(e) => write!(f, "failed to read .vox: {}", e),
+            VoxLoadError::Parse(e) => write!(f, "failed to parse .vox: {}", e),
+            VoxLoadError::TooManyBlocks { limit } => write!(f, "scene needs more than {} distinct blocks, raise --max-blocks if GPU can hold them", limit),
+        }
+    }
+}
+
+impl std::error::Error for VoxLoadError {}
+
+impl From<Error> for VoxLoadError {
+    fn from(e: Error) -> Self {
+        VoxLoadError::Io(e)
+    }
+}
+
+pub fn load_vox(path: impl AsRef<Path>) -> Result<DotVoxData, VoxLoadError> {
+    let bytes = fs::read(path)?;
+    load_vox_bytes(&bytes)
+}
+
+pub fn load_vox_bytes(bytes: &[u8]) -> Result<DotVoxData, VoxLoadError> {
+    dot_vox::load_bytes(bytes).map_err(VoxLoadError::Parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dot_vox::{Color, Dict, Frame, Layer, Material, Model, SceneNode, ShapeModel, Size, Voxel, DEFAULT_INDEX_MAP, DEFAULT_PALETTE};
+    use glam::{IVec3, Vec4};
+
+    use crate::world::{VoxelID, World};
+
+    ///scene of given models with default palette, no scene graph (every model at origin)
+    fn scene(models: Vec<Model>) -> DotVoxData {
+        DotVoxData {
+            version: 150,
+            index_map: DEFAULT_INDEX_MAP.to_vec(),
+            models,
+            palette: DEFAULT_PALETTE.to_vec(),
+            materials: Vec::new(),
+            scenes: Vec::new(),
+            layers: Vec::new(),
+        }
+    }
+
+    ///what MagicaVoxel would save, read back the way load_vox reads files
+    fn round_trip(scene: &DotVoxData) -> DotVoxData {
+        let mut bytes = Vec::new();
+        scene.write_vox(&mut bytes).unwrap();
+        load_vox_bytes(&bytes).unwrap()
+    }
+
+    fn dict(pairs: &[(&str, &str)]) -> Dict {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn missing_file_is_io_error() {
+        match load_vox("assets/does_not_exist.vox") {
+            Err(VoxLoadError::Io(e)) => assert_eq!(e.kind(), ErrorKind::NotFound),
+            other => panic!("expected io error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn garbage_is_parse_error() {
+        let err = load_vox_bytes(b"definitely not a vox file").unwrap_err();
+        assert!(matches!(err, VoxLoadError::Parse(_)), "{}", err);
+    }
+
+    #[test]
+    fn too_many_blocks_is_reported() {
+        //voxels 20 apart land in two blocks, plus the empty one
+        let model = Model {
+            size: Size {x: 32, y: 1, z: 1},
+            voxels: vec![Voxel {x: 0, y: 0, z: 0, i: 1}, Voxel {x: 20, y: 0, z: 0, i: 2}],
+        };
+        let scene = round_trip(&scene(vec![model]));
+
+        let mut world = World::new();
+        world.max_blocks = 3;
+        world.load_map(&scene).unwrap();
+
+        let mut world = World::new();
+        world.max_blocks = 2;
+        let err = world.load_map(&scene).unwrap_err();
+        assert!(matches!(err, VoxLoadError::TooManyBlocks {limit: 2}), "{}", err);
+    }
+
+    #[test]
+    fn shape_is_rotated_and_translated() {
+        //3x2x1 model with two voxels in opposite corners
+        let model = Model {
+            size: Size {x: 3, y: 2, z: 1},
+            voxels: vec![Voxel {x: 0, y: 0, z: 0, i: 0}, Voxel {x: 2, y: 1, z: 0, i: 4}],
+        };
+        let mut scene = scene(vec![model]);
+        //root transform -> group -> transform with frame -> shape, like MagicaVoxel saves it
+        //_r 17: first row picks -y, second row picks x, so 90 degrees counterclockwise around Z
+        scene.scenes = vec![
+            SceneNode::Transform {attributes: Dict::new(), frames: vec![Frame::new(Dict::new())], child: 1, layer_id: u32::MAX},
+            SceneNode::Group {attributes: Dict::new(), children: vec![2]},
+            SceneNode::Transform {attributes: Dict::new(), frames: vec![Frame::new(dict(&[("_r", "17"), ("_t", "10 20 30")]))], child: 3, layer_id: 0},
+            SceneNode::Shape {attributes: Dict::new(), models: vec![ShapeModel {model_id: 0, attributes: Dict::new()}]},
+        ];
+        scene.layers = vec![Layer {attributes: Dict::new()}];
+
+        let mut world = World::new();
+        world.load_map(&round_trip(&scene)).unwrap();
+        //voxel centers relative to model center (1.5, 1, 0.5) are (-1, -0.5, 0) and (1, 0.5, 0),
+        //rotated (x, y) -> (-y, x) they are (0.5, -1, 0) and (-0.5, 1, 0), then moved by _t
+        assert_eq!(world.get_voxel(IVec3::new(10, 19, 30)), VoxelID::from_palette_index(0));
+        assert_eq!(world.get_voxel(IVec3::new(9, 21, 30)), VoxelID::from_palette_index(4));
+        let solid = (0..40*40*40).map(|i| IVec3::new(i % 40, i / 40 % 40, i / 1600))
+            .filter(|pos| !world.get_voxel(*pos).is_empty())
+            .count();
+        assert_eq!(solid, 2);
+    }
+
+    #[test]
+    fn palette_and_materials_are_imported() {
+        let mut scene = scene(Vec::new());
+        scene.palette[3] = Color {r: 255, g: 51, b: 0, a: 255};
+        //MATL ids are file palette indices, palette[3] is file index 4
+        scene.materials = vec![
+            Material {id: 4, properties: dict(&[("_type", "_emit"), ("_emit", "0.5"), ("_flux", "1"), ("_rough", "0.25")])},
+            Material {id: 5, properties: dict(&[("_type", "_metal"), ("_metal", "0.75"), ("_rough", "0.5")])},
+        ];
+
+        let mut world = World::new();
+        world.load_map(&round_trip(&scene)).unwrap();
+        let emissive = &world.voxel_palette[VoxelID::from_palette_index(3).0 as usize];
+        assert_eq!(emissive.color, Vec4::new(1.0, 0.2, 0.0, 1.0));
+        assert_eq!((emissive.emmitance, emissive.roughness, emissive.metalness), (1.0, 0.25, 0.0));
+        let metal = &world.voxel_palette[VoxelID::from_palette_index(4).0 as usize];
+        assert_eq!((metal.emmitance, metal.roughness, metal.metalness), (0.0, 0.5, 0.75));
+        assert!(world.voxel_palette[0].color.w == 0.0, "empty voxel stays invisible");
+    }
+}
@@ -0,0 +1,398 @@
+use std::collections::HashMap;
+use std::slice;
+
+use block_mesh::{ndshape::ConstShape3u32, GreedyQuadsBuffer, UnitQuadBuffer, UnorientedQuad, VoxelVisibility, RIGHT_HANDED_Y_UP_CONFIG};
+use glam::{IVec3, Vec3};
+
+use crate::{ao, ogt::{self, ogt_mesh_rgba}, world::{VoxelID, BLOCK_SIZE}, MyVertex};
+
+///which algorithm turns block voxels into triangles
+/// every one of them outputs indexed triangle list of MyVertex in block-local space, mat is VoxelID, ao is baked
+/// faces hidden by voxels of neighbouring blocks (padding of input) are culled
+/// merging ones never merge faces with different AO (see ao::merge_keys)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mesher {
+    ///ogt_voxel_meshify, one quad per visible face
+    OgtSimple,
+    ///ogt_voxel_meshify, same-color faces merged into rectangles
+    OgtGreedy,
+    ///ogt_voxel_meshify, same-color faces polygonized and triangulated
+    OgtPolygon,
+    ///ogt_voxel_meshify simple with normals averaged over faces meeting at every corner (smooth_normals),
+    /// the only one whose normals are not axis aligned. Border faces take part, so blocks join smoothly
+    OgtSimpleSmooth,
+    ///block_mesh greedy_quads, same-color faces merged into rectangles
+    BlockMeshGreedy,
+    ///block_mesh greedy_quads merging faces of any color. Quads get mat MAT_FROM_VOLUME,
+    /// lighting pass reads actual color of every pixel from block_palette_image
+    BlockMeshGreedyAcrossColors,
+    ///block_mesh visible_block_faces, one quad per visible face
+    BlockMeshVisible,
+}
+
+///indexed triangle list, every 3 indices (into vertices) are one triangle
+#[derive(Clone, Default)]
+pub struct BlockMesh {
+    pub vertices: Vec<MyVertex>,
+    pub indices: Vec<u32>,
+}
+
+impl block_mesh::Voxel for VoxelID {
+    fn get_visibility(&self) -> VoxelVisibility {
+        if self.is_empty() {
+            VoxelVisibility::Empty
+        } else {
+            VoxelVisibility::Opaque
+        }
+    }
+}
+impl block_mesh::MergeVoxel for VoxelID {
+    type MergeValue = Self;
+
+    fn merge_value(&self) -> Self::MergeValue {
+        *self
+    }
+}
+
+///MyVertex::mat of quads that cover voxels of different colors, never a real VoxelID since empty voxels have no faces
+pub const MAT_FROM_VOLUME: u8 = VoxelID::EMPTY.0;
+///voxel as block_mesh sees it, so greedy_quads can keep differently occluded faces apart
+#[derive(Clone, Copy)]
+struct MeshVoxel {
+    id: VoxelID,
+    ///ao::merge_keys
+    ao_key: u32,
+    ///merge with any non-empty voxel, not just same VoxelID
+    any_color: bool,
+}
+impl block_mesh::Voxel for MeshVoxel {
+    fn get_visibility(&self) -> VoxelVisibility {
+        self.id.get_visibility()
+    }
+}
+impl block_mesh::MergeVoxel for MeshVoxel {
+    type MergeValue = (VoxelID, u32);
+
+    fn merge_value(&self) -> Self::MergeValue {
+        let material = if self.any_color {VoxelID::EMPTY} else {self.id.merge_value()};
+        (material, self.ao_key)
+    }
+}
+///side of block with one voxel of every neighbour around it
+pub const PADDED: usize = BLOCK_SIZE + 2;
+pub const PADDED_VOLUME: usize = PADDED*PADDED*PADDED;
+type BlockShape = ConstShape3u32<{PADDED as u32}, {PADDED as u32}, {PADDED as u32}>;
+
+///index of pos (block-local, -1..=BLOCK_SIZE) in padded block
+pub fn padded_index(pos: IVec3) -> usize {
+    let p = (pos + IVec3::ONE).as_uvec3();
+    p.x as usize + p.y as usize*PADDED + p.z as usize*PADDED*PADDED
+}
+
+///is padded block position (0..PADDED) inside of block itself, not its padding
+fn is_core(pos: IVec3) -> bool {
+    pos.cmpge(IVec3::ONE).all() && pos.cmple(IVec3::splat(BLOCK_SIZE as i32)).all()
+}
+
+impl Mesher {
+    pub const ALL: [Mesher; 7] = [Mesher::OgtSimple, Mesher::OgtGreedy, Mesher::OgtPolygon, Mesher::OgtSimpleSmooth, Mesher::BlockMeshGreedy, Mesher::BlockMeshGreedyAcrossColors, Mesher::BlockMeshVisible];
+
+    ///padded is PADDED^3 (see padded_index), order is X -> Y -> Z. Block voxels are inside of it,
+    /// border holds touching voxels of neighbouring blocks, only used for culling and AO
+    pub fn mesh_block(&self, padded: &[VoxelID]) -> BlockMesh {
+        //heap context without budget only fails when whole process is out of memory
+        self.mesh_block_with(&ogt::Context::new(), padded).expect("failed to mesh block")
+    }
+
+    ///mesh_block with given ogt context, e.g. the one meshing thread keeps for all of its blocks
+    /// fails if ctx refuses an allocation (budget or arena), block_mesh ones never do
+    pub fn mesh_block_with(&self, ctx: &ogt::Context, padded: &[VoxelID]) -> Result<BlockMesh, ogt::MeshError> {
+        let mut mesh = match self {
+            Mesher::OgtSimple | Mesher::OgtGreedy | Mesher::OgtPolygon | Mesher::OgtSimpleSmooth => mesh_block_ogt(*self, ctx, padded)?,
+            Mesher::BlockMeshGreedy | Mesher::BlockMeshGreedyAcrossColors | Mesher::BlockMeshVisible => mesh_block_block_mesh(*self, padded),
+        };
+        ao::bake_ao(&mut mesh, padded);
+        Ok(mesh)
+    }
+}
+
+impl std::str::FromStr for Mesher {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "simple" => Ok(Mesher::OgtSimple),
+            "greedy" => Ok(Mesher::OgtGreedy),
+            "polygon" => Ok(Mesher::OgtPolygon),
+            "simple-smooth" => Ok(Mesher::OgtSimpleSmooth),
+            "block-mesh-greedy" => Ok(Mesher::BlockMeshGreedy),
+            "block-mesh-greedy-across-colors" => Ok(Mesher::BlockMeshGreedyAcrossColors),
+            "block-mesh-visible" => Ok(Mesher::BlockMeshVisible),
+            _ => Err(format!("unknown mesher {}", s)),
+        }
+    }
+}
+
+fn mesh_block_block_mesh(mesher: Mesher, padded: &[VoxelID]) -> BlockMesh {
+    let mut mesh = BlockMesh::default();
+    //used as temporary storage for meshification
+    //block_mesh never emits faces of border voxels, they only hide faces of block ones
+    let any_color = mesher == Mesher::BlockMeshGreedyAcrossColors;
+    let mut temp_block: Vec<MeshVoxel> = padded.iter().map(|id| MeshVoxel {id: *id, ao_key: 0, any_color}).collect();
+    let ao_keys = ao::merge_keys(padded);
+    for x in 0..BLOCK_SIZE{
+    for y in 0..BLOCK_SIZE{
+    for z in 0..BLOCK_SIZE{
+        let i = x + BLOCK_SIZE*y + BLOCK_SIZE*BLOCK_SIZE*z;
+        temp_block[padded_index(IVec3::new(x as i32, y as i32, z as i32))].ao_key = ao_keys[i];
+    }
+    }
+    }
+
+    let groups: [Vec<UnorientedQuad>; 6] = if mesher != Mesher::BlockMeshVisible {
+        let mut current_buffer = GreedyQuadsBuffer::new(temp_block.len());
+        block_mesh::greedy_quads(&temp_block, &BlockShape{}, [0; 3], [PADDED as u32 - 1; 3], &RIGHT_HANDED_Y_UP_CONFIG.faces, &mut current_buffer);
+        current_buffer.quads.groups
+    } else {
+        let mut current_buffer = UnitQuadBuffer::new();
+        block_mesh::visible_block_faces(&temp_block, &BlockShape{}, [0; 3], [PADDED as u32 - 1; 3], &RIGHT_HANDED_Y_UP_CONFIG.faces, &mut current_buffer);
+        current_buffer.groups.map(|group| group.into_iter().map(UnorientedQuad::from).collect())
+    };
+
+    for (face_dir, face_group) in RIGHT_HANDED_Y_UP_CONFIG.faces.iter().zip(&groups){
+        let normals = face_dir.quad_mesh_normals();
+
+        for &quad in face_group{
+            //temp_block is padded by 1, so shift back into block space
+            let positions = face_dir.quad_mesh_positions(&quad, 1.0).map(|p| [p[0]-1.0, p[1]-1.0, p[2]-1.0]);
+
+            //quad corners may lie in neighbouring (or empty) voxels, so material is taken from voxel the face belongs to
+            //(every voxel of quad has it, unless colors were merged)
+            let [x, y, z] = quad.minimum.map(|c| c as usize);
+            let mat = if any_color {MAT_FROM_VOLUME} else {temp_block[x + PADDED*y + PADDED*PADDED*z].id.0};
+
+            let start = mesh.vertices.len() as u32;
+            for corner in 0..4 {
+                mesh.vertices.push(MyVertex {position: positions[corner], normal: normals[corner], mat, ao: ao::AO_NONE});
+            }
+            //wound counterclockwise seen from where normal points
+            mesh.indices.extend(face_dir.quad_mesh_indices(start));
+        }
+    };
+    mesh
+}
+
+///vertices and indices stream_simple writes for voxels of given size
+pub fn stream_capacity(voxels: &[VoxelID], size: [u32; 3]) -> Result<(usize, usize), ogt::MeshError> {
+    let faces = ogt::face_count_simple(voxels_as_bytes(voxels), size)?;
+    Ok((faces * 4, faces * 6))
+}
+
+///ogt simple mesh of whole volume (order X -> Y -> Z, any size) written straight into vertices and indices,
+/// e.g. mapped staging buffers. They need stream_capacity room. Nothing is culled against outside of volume and no AO is baked
+pub fn stream_simple(voxels: &[VoxelID], size: [u32; 3], vertices: &mut [MyVertex], indices: &mut [u32]) -> Result<(), ogt::MeshError> {
+    let (vertex_count, index_count) = stream_capacity(voxels, size)?;
+    assert!(vertices.len() >= vertex_count && indices.len() >= index_count, "stream_simple needs {} vertices and {} indices", vertex_count, index_count);
+    //colors are not used, palette_index is VoxelID
+    let ogt_palette = [ogt_mesh_rgba {r:4,g:3,b:2,a:1}; 256];
+    let (mut vertex_i, mut index_i) = (0, 0);
+    ogt::stream_simple(voxels_as_bytes(voxels), size, &ogt_palette, |_, streamed, streamed_indices| {
+        for (out, vertex) in vertices[vertex_i..].iter_mut().zip(streamed) {
+            *out = MyVertex {
+                position: [vertex.pos.x, vertex.pos.y, vertex.pos.z],
+                normal: [vertex.normal.x, vertex.normal.y, vertex.normal.z],
+                mat: vertex.palette_index as u8,
+                ao: ao::AO_NONE,
+            };
+        }
+        indices[index_i..index_i + streamed_indices.len()].copy_from_slice(streamed_indices);
+        vertex_i += streamed.len();
+        index_i += streamed_indices.len();
+    })
+}
+
+pub(crate) fn voxels_as_bytes(voxels: &[VoxelID]) -> &[u8] {
+    //VoxelID is repr(transparent) over u8
+    unsafe { slice::from_raw_parts(voxels.as_ptr() as *const u8, voxels.len()) }
+}
+
+///ogt merges by palette index only, so every (VoxelID, AO merge key) pair gets index of its own
+/// border voxels all share one index, so no face of block gets merged with face of its neighbour
+/// returns padded voxels as those indices and VoxelID of each index, None if block has more than 255 pairs
+fn ao_classes(padded: &[VoxelID]) -> Option<(Vec<u8>, Vec<VoxelID>)> {
+    const BORDER: u8 = 1;
+    let mut ids = vec![VoxelID::EMPTY, VoxelID::EMPTY];
+    let mut classes = HashMap::new();
+    let keys = ao::merge_keys(padded);
+    let mut indices = vec![0; padded.len()];
+    for (i, voxel) in padded.iter().enumerate() {
+        let pos = IVec3::new((i % PADDED) as i32, (i / PADDED % PADDED) as i32, (i / (PADDED*PADDED)) as i32);
+        if voxel.is_empty() {
+            continue;
+        }
+        if !is_core(pos) {
+            indices[i] = BORDER;
+            continue;
+        }
+        let local = (pos - IVec3::ONE).as_uvec3();
+        let key = keys[local.x as usize + local.y as usize*BLOCK_SIZE + local.z as usize*BLOCK_SIZE*BLOCK_SIZE];
+        indices[i] = match classes.get(&(*voxel, key)) {
+            Some(class) => *class,
+            None if ids.len() < 256 => {
+                ids.push(*voxel);
+                classes.insert((*voxel, key), ids.len() as u8 - 1);
+                ids.len() as u8 - 1
+            }
+            None => return None,
+        };
+    }
+    Some((indices, ids))
+}
+
+///keeps only triangles of block voxels (ogt meshes border too) and moves them from padded into block space
+fn drop_border_faces(mesh: BlockMesh) -> BlockMesh {
+    let mut kept = BlockMesh::default();
+    let mut remap = vec![u32::MAX; mesh.vertices.len()];
+    for triangle in mesh.indices.chunks(3) {
+        //voxel just behind centroid is the one triangle belongs to, merged faces never mix block and border voxels
+        //behind is taken from winding, vertex normals may be smoothed
+        let [a, b, c] = [0, 1, 2].map(|corner| Vec3::from(mesh.vertices[triangle[corner] as usize].position));
+        let facing = (b - a).cross(c - a).normalize();
+        let owner = ((a + b + c) / 3.0 - facing * 0.5).floor().as_ivec3();
+        if !is_core(owner) {
+            continue;
+        }
+        for i in triangle {
+            if remap[*i as usize] == u32::MAX {
+                remap[*i as usize] = kept.vertices.len() as u32;
+                let mut vertex = mesh.vertices[*i as usize];
+                vertex.position = (Vec3::from(vertex.position) - Vec3::ONE).into();
+                kept.vertices.push(vertex);
+            }
+            kept.indices.push(remap[*i as usize]);
+        }
+    }
+    kept
+}
+
+fn mesh_block_ogt(mesher: Mesher, ctx: &ogt::Context, padded: &[VoxelID]) -> Result<BlockMesh, ogt::MeshError> {
+    //we dont need colors directly so initialization is unnesessary. We'll just use material index from resulting mesh
+    //(greedy and polygon merge by palette index, not by color)
+    let ogt_palette = [ogt_mesh_rgba {r:4,g:3,b:2,a:1}; 256];
+    //ogt has no notion of padding: whole padded block is meshed and faces of border dropped after
+    let size = [PADDED as u32; 3];
+
+    //merging ones get AO classes instead of voxels, too many classes means faces can not be merged at all
+    let classes = match mesher {
+        Mesher::OgtGreedy | Mesher::OgtPolygon => ao_classes(padded),
+        _ => None,
+    };
+    let bytes = match &classes {
+        Some((indices, _)) => indices.as_slice(),
+        None => voxels_as_bytes(padded),
+    };
+
+    let mut mesh = match (mesher, &classes) {
+        (Mesher::OgtGreedy, Some(_)) => ctx.mesh_greedy(bytes, size, &ogt_palette),
+        (Mesher::OgtPolygon, Some(_)) => ctx.mesh_polygon(bytes, size, &ogt_palette),
+        _ => ctx.mesh_simple(bytes, size, &ogt_palette),
+    }?;
+    //ogt emits 4 vertices per quad, corners shared by faces of the same color and direction collapse into one
+    mesh.remove_duplicate_vertices()?;
+    if mesher == Mesher::OgtSimpleSmooth {
+        mesh.smooth_normals()?;
+    }
+
+    Ok(drop_border_faces(BlockMesh {
+        vertices: mesh.vertices().iter().map(|vertex| MyVertex {
+            position: [vertex.pos.x, vertex.pos.y, vertex.pos.z],
+            normal: [vertex.normal.x, vertex.normal.y, vertex.normal.z],
+            mat: match &classes {
+                Some((_, ids)) => ids[vertex.palette_index as usize].0,
+                None => vertex.palette_index as u8,
+            },
+            ao: ao::AO_NONE,
+        }).collect(),
+        indices: mesh.indices().to_vec(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///two-colored 4x3x2 box inside of block, with voxel of neighbouring block touching it in padding
+    fn small_model() -> Vec<VoxelID> {
+        let mut padded = vec![VoxelID::EMPTY; PADDED_VOLUME];
+        for x in 5..9 {
+        for y in 6..9 {
+        for z in 0..2 {
+            padded[padded_index(IVec3::new(x, y, z))] = VoxelID(if x < 7 {3} else {9});
+        }
+        }
+        }
+        padded[padded_index(IVec3::new(5, 6, -1))] = VoxelID(4);
+        padded
+    }
+
+    #[test]
+    fn every_mesher_meshes_inside_of_block() {
+        let padded = small_model();
+        for mesher in Mesher::ALL {
+            let mesh = mesher.mesh_block(&padded);
+            assert!(!mesh.indices.is_empty(), "{:?} made no triangles", mesher);
+            assert_eq!(mesh.indices.len() % 3, 0, "{:?}", mesher);
+            assert!(mesh.indices.iter().all(|i| (*i as usize) < mesh.vertices.len()), "{:?} indexes past vertices", mesher);
+            for vertex in &mesh.vertices {
+                //the box spans 5..9, 6..9, 0..2 of block, padding voxel only hides faces
+                let position = Vec3::from(vertex.position);
+                assert!(position.cmpge(Vec3::new(5.0, 6.0, 0.0)).all() && position.cmple(Vec3::new(9.0, 9.0, 2.0)).all(),
+                    "{:?} vertex {} outside of model", mesher, position);
+                let allowed: &[u8] = if mesher == Mesher::BlockMeshGreedyAcrossColors {&[MAT_FROM_VOLUME]} else {&[3, 9]};
+                assert!(allowed.contains(&vertex.mat), "{:?} made mat {}", mesher, vertex.mat);
+            }
+        }
+    }
+
+    #[test]
+    fn triangles_are_wound_like_their_normal() {
+        let padded = small_model();
+        for mesher in Mesher::ALL {
+            let mesh = mesher.mesh_block(&padded);
+            for triangle in mesh.indices.chunks(3) {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize]);
+                let [pa, pb, pc] = [a, b, c].map(|v| Vec3::from(v.position));
+                //every triangle faces where its normal points, so both halves of a quad are culled alike
+                //smoothed normals lean towards neighbouring faces, but stay on the same side
+                let min_dot = if mesher == Mesher::OgtSimpleSmooth {0.0} else {0.99};
+                let winding = (pb - pa).cross(pc - pa).normalize();
+                assert!(winding.dot(Vec3::from(a.normal)) > min_dot, "{:?}: triangle {:?} faces {} against normal {:?}", mesher, triangle, winding, a.normal);
+            }
+        }
+    }
+
+    #[test]
+    fn smooth_normals_lean_over_box_edges() {
+        let padded = small_model();
+        let flat = Mesher::OgtSimple.mesh_block(&padded);
+        let smooth = Mesher::OgtSimpleSmooth.mesh_block(&padded);
+        assert_eq!(smooth.indices.len(), flat.indices.len());
+        for vertex in &smooth.vertices {
+            assert!((Vec3::from(vertex.normal).length() - 1.0).abs() < 1e-5);
+        }
+        //top corner of the box, where +X, +Y and +Z faces meet
+        let corner = smooth.vertices.iter().find(|v| v.position == [9.0, 9.0, 2.0]).unwrap();
+        assert!((Vec3::from(corner.normal) - Vec3::ONE.normalize()).length() < 1e-5, "{:?}", corner.normal);
+    }
+
+    #[test]
+    fn every_mesher_parses_and_unknown_is_rejected() {
+        let names = ["simple", "greedy", "polygon", "simple-smooth", "block-mesh-greedy", "block-mesh-greedy-across-colors", "block-mesh-visible"];
+        let parsed: Vec<Mesher> = names.iter().map(|name| name.parse().unwrap()).collect();
+        assert_eq!(parsed, Mesher::ALL);
+        for name in ["", "Simple", "greedy ", "marching-cubes"] {
+            let err = name.parse::<Mesher>().unwrap_err();
+            assert!(err.contains(name), "{}", err);
+        }
+    }
+}
@@ -5,44 +5,207 @@ extern crate core;
 
 pub mod loader;
 pub mod world;
-pub mod ogt_voxel_meshify;
+pub mod ogt;
+pub mod mesher;
+pub mod headless;
+pub mod camera;
+pub mod controls;
+pub mod tracer;
+pub mod cpu_tracer;
+pub mod bench;
+pub mod ao;
+pub mod jobs;
 
-use std::{ops::RangeInclusive, process::Command, sync::Arc};
+use std::{collections::HashMap, ops::RangeInclusive, process::Command, sync::Arc};
 use std::convert::TryInto;
 use std::convert::TryFrom;
 
-use vulkano::{buffer::{BufferContents, Subbuffer}, device::{DeviceCreateInfo, QueueCreateInfo}, format::Format, image::{ImageCreateInfo, ImageUsage}, instance::{debug::{DebugUtilsMessenger, DebugUtilsMessengerCallback, DebugUtilsMessengerCreateInfo}, InstanceCreateInfo}, memory::allocator::{AllocationCreateInfo, MemoryAllocator, StandardMemoryAllocator}, pipeline::graphics::{depth_stencil::{CompareOp, DepthState, DepthStencilState}, rasterization::CullMode}, swapchain::{self, SwapchainCreateInfo}};
+use glam::{IVec3, Mat4, Vec3};
+use vulkano::{buffer::{Buffer, BufferContents, BufferCreateInfo, BufferUsage, Subbuffer}, command_buffer::{CopyBufferInfo, PrimaryCommandBufferAbstract}, memory::allocator::MemoryTypeFilter, sync::GpuFuture, DeviceSize, device::{DeviceCreateInfo, QueueCreateInfo}, format::Format, image::{ImageCreateInfo, ImageUsage}, instance::{debug::{DebugUtilsMessenger, DebugUtilsMessengerCallback, DebugUtilsMessengerCreateInfo}, InstanceCreateInfo}, memory::allocator::{AllocationCreateInfo, MemoryAllocator, StandardMemoryAllocator}, pipeline::graphics::{depth_stencil::{CompareOp, DepthState, DepthStencilState}, rasterization::CullMode}, swapchain::{self, SwapchainCreateInfo}};
 use vulkano::command_buffer::allocator::StandardCommandBufferAllocator;
+use vulkano::descriptor_set::{allocator::StandardDescriptorSetAllocator, PersistentDescriptorSet, WriteDescriptorSet};
 use vulkano::command_buffer::{AutoCommandBufferBuilder, CommandBufferUsage, PrimaryAutoCommandBuffer, RenderPassBeginInfo, SubpassBeginInfo, SubpassContents};
 use vulkano::device::physical::{PhysicalDevice, PhysicalDeviceType};
 use vulkano::device::{Device, DeviceExtensions, Queue, QueueFlags};
+use vulkano::image::sampler::{Sampler, SamplerCreateInfo};
 use vulkano::image::view::ImageView;
 use vulkano::image::Image;
-use vulkano::instance::Instance;
+use vulkano::instance::{Instance, InstanceExtensions};
 use vulkano::pipeline::graphics::color_blend::{ColorBlendAttachmentState, ColorBlendState};
 use vulkano::pipeline::graphics::input_assembly::InputAssemblyState;
 use vulkano::pipeline::graphics::multisample::MultisampleState;
 use vulkano::pipeline::graphics::rasterization::RasterizationState;
-use vulkano::pipeline::graphics::vertex_input::{Vertex, VertexDefinition};
+use vulkano::pipeline::graphics::vertex_input::{Vertex, VertexDefinition, VertexInputState};
 use vulkano::pipeline::graphics::viewport::{Viewport, ViewportState};
 use vulkano::pipeline::graphics::GraphicsPipelineCreateInfo;
 use vulkano::pipeline::layout::PipelineDescriptorSetLayoutCreateInfo;
-use vulkano::pipeline::{GraphicsPipeline, PipelineLayout, PipelineShaderStageCreateInfo};
+use vulkano::pipeline::{GraphicsPipeline, Pipeline, PipelineBindPoint, PipelineLayout, PipelineShaderStageCreateInfo};
 use vulkano::render_pass::{Framebuffer, FramebufferCreateInfo, RenderPass, Subpass};
 use vulkano::shader::ShaderModule;
 use vulkano::swapchain::{Surface, Swapchain};
 use winit::{event_loop::EventLoop, window::{Window, WindowBuilder}};
 
 
-#[derive(BufferContents, Vertex, Clone, Copy)]
+#[derive(BufferContents, Vertex, Clone, Copy, Default)]
 #[repr(C)]
 pub struct MyVertex {
     #[format(R32G32B32_SFLOAT)]
     pub position: [f32; 3],
     #[format(R32G32B32_SFLOAT)]
     pub normal: [f32; 3],
+    ///VoxelID, or mesher::MAT_FROM_VOLUME if face covers voxels of different colors
     #[format(R8_UINT)]
     pub mat: u8,
+    ///0 (fully occluded) ..= 3 (open), see ao.rs
+    #[format(R8_UINT)]
+    pub ao: u8,
+}
+
+///axis normals PackedVertex can store, indexed by its normal index. Same as NORMALS in v_packed.vert
+pub const NORMALS: [[f32; 3]; 6] = [[1.0, 0.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, -1.0]];
+
+///MyVertex in 5 bytes instead of 28. Voxel meshes only have integer chunk-local positions
+/// (0..=CHUNK_SIZE*BLOCK_SIZE, fits u8) and axis normals. Has to match inputs of v_packed.vert
+#[derive(BufferContents, Vertex, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct PackedVertex {
+    ///x, y, z and index into NORMALS (low 3 bits) with ao above it
+    #[format(R8G8B8A8_UINT)]
+    pub position_normal: [u8; 4],
+    #[format(R8_UINT)]
+    pub mat: u8,
+}
+
+impl PackedVertex {
+    ///position is rounded, normal snapped to closest axis
+    pub fn pack(vertex: &MyVertex) -> PackedVertex {
+        let [x, y, z] = vertex.position.map(|c| {
+            debug_assert!(c >= 0.0 && c <= 255.0 && c.fract() == 0.0, "{} can not be packed", c);
+            c.round() as u8
+        });
+        let normal = (0..NORMALS.len())
+            .max_by(|a, b| Vec3::from(NORMALS[*a]).dot(Vec3::from(vertex.normal)).total_cmp(&Vec3::from(NORMALS[*b]).dot(Vec3::from(vertex.normal))))
+            .unwrap();
+        PackedVertex {position_normal: [x, y, z, normal as u8 | vertex.ao << 3], mat: vertex.mat}
+    }
+
+    ///what v_packed.vert reads back out of it
+    #[cfg(test)]
+    pub fn unpack(&self) -> MyVertex {
+        let [x, y, z, normal_ao] = self.position_normal;
+        MyVertex {
+            position: [x as f32, y as f32, z as f32],
+            normal: NORMALS[(normal_ao & 7) as usize],
+            mat: self.mat,
+            ao: normal_ao >> 3,
+        }
+    }
+}
+
+///which vertex struct chunk meshes are uploaded as. CPU side always keeps MyVertex
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VertexFormat {
+    ///MyVertex, drawn with v.vert
+    Full,
+    ///PackedVertex, drawn with v_packed.vert
+    Packed,
+    ///MyVertex streamed by ogt simple from chunk voxels straight into staging buffer (upload_streamed), drawn with v.vert
+    /// CPU mesh is not uploaded: no AO and faces against neighbouring chunks are kept
+    Streamed,
+}
+
+impl VertexFormat {
+    pub fn vertex_size(self) -> usize {
+        match self {
+            VertexFormat::Full | VertexFormat::Streamed => std::mem::size_of::<MyVertex>(),
+            VertexFormat::Packed => std::mem::size_of::<PackedVertex>(),
+        }
+    }
+
+    ///compiled G-buffer vertex shader that reads this format
+    pub fn vertex_shader(self) -> &'static str {
+        match self {
+            VertexFormat::Full | VertexFormat::Streamed => "shaders/vert.spv",
+            VertexFormat::Packed => "shaders/vert_packed.spv",
+        }
+    }
+}
+
+impl std::str::FromStr for VertexFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(VertexFormat::Full),
+            "packed" => Ok(VertexFormat::Packed),
+            "streamed" => Ok(VertexFormat::Streamed),
+            _ => Err(format!("unknown vertex format {}", s)),
+        }
+    }
+}
+
+///one entry of voxel_palette as shader sees it (std430), has to match Material in shaders/voxel.glsl
+#[derive(BufferContents, Clone, Copy)]
+#[repr(C)]
+pub struct MaterialGPU {
+    pub color: [f32; 4],
+    pub emmitance: f32,
+    pub roughness: f32,
+    pub metalness: f32,
+    pub ior: f32,
+    pub transparency: f32,
+    pub _pad: [f32; 3],
+}
+
+impl From<&world::Material> for MaterialGPU {
+    fn from(material: &world::Material) -> Self {
+        MaterialGPU {
+            color: material.color.to_array(),
+            emmitance: material.emmitance,
+            roughness: material.roughness,
+            metalness: material.metalness,
+            ior: material.ior,
+            transparency: material.transparency,
+            _pad: [0.0; 3],
+        }
+    }
+}
+
+///per-draw data, has to match push_constant block in v.vert
+#[derive(BufferContents, Clone, Copy)]
+#[repr(C)]
+pub struct PushConstants {
+    ///chunk-local -> world
+    pub trans: [[f32; 4]; 4],
+    ///world -> clip, Camera::view_proj
+    pub view_proj: [[f32; 4]; 4],
+}
+
+///has to match push_constant block in light.frag
+#[derive(BufferContents, Clone, Copy)]
+#[repr(C)]
+pub struct LightPushConstants {
+    ///VolumeGPU::origin, w unused
+    pub volume_origin: [i32; 4],
+    ///VolumeGPU::size, w unused
+    pub volume_size: [i32; 4],
+}
+
+///GPU side of one loaded chunk
+#[derive(Clone)]
+pub struct ChunkGPU {
+    pub vertices: ChunkVertices,
+    ///same as MeshCPU::indices
+    pub indices: Subbuffer<[u32]>,
+    ///same as MeshCPU::trans
+    pub trans: Mat4,
+}
+
+///vertex buffer of chunk in whatever VertexFormat it was uploaded as
+#[derive(Clone)]
+pub enum ChunkVertices {
+    Full(Subbuffer<[MyVertex]>),
+    Packed(Subbuffer<[PackedVertex]>),
 }
 
 // #[derive(Debug)]
@@ -81,62 +244,139 @@ pub fn select_physical_device(instance: &Arc<Instance>, surface: &Arc<Surface>,
         }).expect("no device available")
 }
 
-pub fn get_render_pass(device: Arc<Device>, swapchain: Arc<Swapchain>) -> Arc<RenderPass> {
-    vulkano::single_pass_renderpass!(
+///G-buffer format of both pos_mat and normal attachments
+pub const GBUFFER_FORMAT: Format = Format::R32G32B32A32_SFLOAT;
+
+///two subpasses: meshes are rasterized into G-buffer (pos_mat = world position + VoxelID, normal, depth),
+/// then lighting pass reads it as input attachments and writes final color
+///format is format of color attachment (swapchain or offscreen image)
+pub fn get_render_pass(device: Arc<Device>, format: Format) -> Arc<RenderPass> {
+    vulkano::ordered_passes_renderpass!(
         device,
         attachments: {
             color: {
-                format: swapchain.image_format(), // set the format the same as the swapchain
+                format: format, // set the format the same as the swapchain
                 samples: 1,
                 load_op: Clear,
                 store_op: Store,
             },
+            pos_mat: {
+                format: GBUFFER_FORMAT,
+                samples: 1,
+                load_op: Clear,
+                store_op: DontCare,
+            },
+            normal: {
+                format: GBUFFER_FORMAT,
+                samples: 1,
+                load_op: Clear,
+                store_op: DontCare,
+            },
             depth: {
-                format: Format::D16_UNORM, // set the format the same as the swapchain
+                format: Format::D16_UNORM,
                 samples: 1,
                 load_op: Clear,
                 store_op: DontCare,
             }
         },
-        pass: {
-            color: [color],
-            depth_stencil: {depth},
-        },
+        passes: [
+            {
+                color: [pos_mat, normal],
+                depth_stencil: {depth},
+                input: [],
+            },
+            {
+                color: [color],
+                depth_stencil: {},
+                input: [pos_mat, normal],
+            }
+        ],
     ).unwrap()
 }
-
+///attachments are [image, pos_mat, normal, depth], G-buffer ones are created per framebuffer
 pub fn get_framebuffers(images: &[Arc<Image>], render_pass: Arc<RenderPass>, allocator: Arc<dyn MemoryAllocator>) -> Vec<Arc<Framebuffer>> {
+    let attachment = |format: Format, usage: ImageUsage, extent: [u32; 3]| {
+        let image = Image::new(allocator.clone(), ImageCreateInfo {
+            image_type: vulkano::image::ImageType::Dim2d,
+            format,
+            extent,
+            usage,
+            ..Default::default()
+        }, AllocationCreateInfo {
+            ..Default::default()
+        }).unwrap();
+        ImageView::new_default(image).unwrap()
+    };
+
     images
         .iter()
         .map(|image| {
-            let depth_image = Image::new(allocator.clone(), ImageCreateInfo {
-                image_type: vulkano::image::ImageType::Dim2d,
-                format: Format::D16_UNORM,
-                extent: image.extent(),
-                usage: ImageUsage::DEPTH_STENCIL_ATTACHMENT,
-                // initial_layout
-                ..Default::default()
-            }, AllocationCreateInfo {
-                ..Default::default()
-            }).unwrap();
-            let depth_view = ImageView::new_default(depth_image).unwrap();
+            let gbuffer_usage = ImageUsage::COLOR_ATTACHMENT | ImageUsage::INPUT_ATTACHMENT;
+            let pos_mat_view = attachment(GBUFFER_FORMAT, gbuffer_usage, image.extent());
+            let normal_view = attachment(GBUFFER_FORMAT, gbuffer_usage, image.extent());
+            let depth_view = attachment(Format::D16_UNORM, ImageUsage::DEPTH_STENCIL_ATTACHMENT, image.extent());
 
             let view = ImageView::new_default(image.clone()).unwrap();
             Framebuffer::new(
                 render_pass.clone(),
                 FramebufferCreateInfo {
-                    attachments: vec![view, depth_view],
+                    attachments: vec![view, pos_mat_view, normal_view, depth_view],
                     ..Default::default()
                 },
             ).unwrap()
         }).collect::<Vec<_>>()
 }
 
-pub fn get_graphical_pipeline(device: Arc<Device>, vs: Arc<ShaderModule>, fs: Arc<ShaderModule>, render_pass: Arc<RenderPass>, viewport: Viewport) -> Arc<GraphicsPipeline> {
+///fullscreen pass of subpass 1, reads G-buffer
+pub fn get_lighting_pipeline(device: Arc<Device>, vs: Arc<ShaderModule>, fs: Arc<ShaderModule>, render_pass: Arc<RenderPass>, viewport: Viewport) -> Arc<GraphicsPipeline> {
+    let stages = [
+        PipelineShaderStageCreateInfo::new(vs.entry_point("main").unwrap()),
+        PipelineShaderStageCreateInfo::new(fs.entry_point("main").unwrap()),
+    ];
+
+    let layout = PipelineLayout::new(
+        device.clone(),
+        PipelineDescriptorSetLayoutCreateInfo::from_stages(&stages)
+            .into_pipeline_layout_create_info(device.clone())
+            .unwrap(),
+    )
+    .unwrap();
+
+    let subpass = Subpass::from(render_pass.clone(), 1).unwrap();
+
+    GraphicsPipeline::new(
+        device.clone(),
+        None,
+        GraphicsPipelineCreateInfo {
+            stages: exr::prelude::SmallVec::from(stages).into_iter().collect(),
+            //triangle is generated from gl_VertexIndex
+            vertex_input_state: Some(VertexInputState::new()),
+            input_assembly_state: Some(InputAssemblyState::default()),
+            viewport_state: Some(ViewportState {
+                viewports: exr::prelude::SmallVec::from([viewport]).into_iter().collect(),
+                ..Default::default()
+            }),
+            rasterization_state: Some(RasterizationState::default()),
+            multisample_state: Some(MultisampleState::default()),
+            color_blend_state: Some(ColorBlendState::with_attachment_states(
+                subpass.num_color_attachments(),
+                ColorBlendAttachmentState::default(),
+            )),
+            subpass: Some(subpass.into()),
+            ..GraphicsPipelineCreateInfo::layout(layout)
+        },
+    ).unwrap()
+}
+
+///vs has to be format.vertex_shader()
+pub fn get_graphical_pipeline(device: Arc<Device>, vs: Arc<ShaderModule>, fs: Arc<ShaderModule>, render_pass: Arc<RenderPass>, viewport: Viewport, format: VertexFormat) -> Arc<GraphicsPipeline> {
     let vs = vs.entry_point("main").unwrap();
     let fs = fs.entry_point("main").unwrap();
 
-    let vertex_input_state = MyVertex::per_vertex()
+    let vertex_input_state = match format {
+        VertexFormat::Full | VertexFormat::Streamed => MyVertex::per_vertex(),
+        VertexFormat::Packed => PackedVertex::per_vertex(),
+    }
         .definition(&vs.info().input_interface)
         .unwrap();
 
@@ -191,15 +431,128 @@ pub fn get_graphical_pipeline(device: Arc<Device>, vs: Arc<ShaderModule>, fs: Ar
     ).unwrap()
 }
 
+///uploads mesh of every loaded chunk, keyed by chunk position. Empty chunks are skipped, they have nothing to draw (and zero-sized buffers are not allowed)
+pub fn upload_world(
+    memory_allocator: Arc<dyn MemoryAllocator>,
+    command_buffer_allocator: &StandardCommandBufferAllocator,
+    queue: &Arc<Queue>,
+    world: &world::World,
+    format: VertexFormat,
+) -> HashMap<IVec3, ChunkGPU> {
+    let mut chunks = HashMap::new();
+    let positions: Vec<IVec3> = world.chunks.keys().copied().collect();
+    update_chunks(memory_allocator, command_buffer_allocator, queue, world, &positions, format, &mut chunks);
+    chunks
+}
+
+///reuploads given chunks (e.g. World::remesh_dirty output), drops ones that are gone or empty
+pub fn update_chunks(
+    memory_allocator: Arc<dyn MemoryAllocator>,
+    command_buffer_allocator: &StandardCommandBufferAllocator,
+    queue: &Arc<Queue>,
+    world: &world::World,
+    positions: &[IVec3],
+    format: VertexFormat,
+    chunks: &mut HashMap<IVec3, ChunkGPU>,
+) {
+    for pos in positions {
+        chunks.remove(pos);
+        let Some(chunk) = world.chunks.get(pos) else {continue;};
+        if chunk.mesh.indices.is_empty() {continue;}
+        if format == VertexFormat::Streamed {
+            let voxels = world.chunk_voxels(*pos).unwrap();
+            let size = [(world::CHUNK_SIZE*world::BLOCK_SIZE) as u32; 3];
+            //only fails on size mismatch, and chunk_voxels is always that size
+            if let Some((vertices, indices)) = upload_streamed(memory_allocator.clone(), command_buffer_allocator, queue, &voxels, size).unwrap() {
+                chunks.insert(*pos, ChunkGPU {vertices: ChunkVertices::Full(vertices), indices, trans: chunk.mesh.trans});
+            }
+            continue;
+        }
+        chunks.insert(*pos, ChunkGPU {
+            vertices: match format {
+                VertexFormat::Full | VertexFormat::Streamed => ChunkVertices::Full(upload_vertices(memory_allocator.clone(), command_buffer_allocator, queue, &chunk.mesh.vertices)),
+                VertexFormat::Packed => {
+                    let packed: Vec<PackedVertex> = chunk.mesh.vertices.iter().map(PackedVertex::pack).collect();
+                    ChunkVertices::Packed(upload_device_local(memory_allocator.clone(), command_buffer_allocator, queue, &packed, BufferUsage::VERTEX_BUFFER))
+                }
+            },
+            indices: upload_indices(memory_allocator.clone(), command_buffer_allocator, queue, &chunk.mesh.indices),
+            trans: chunk.mesh.trans,
+        });
+    }
+}
+
+///voxel_palette as storage buffer for shaders
+pub fn upload_palette(memory_allocator: Arc<dyn MemoryAllocator>, palette: &[world::Material]) -> Subbuffer<[MaterialGPU]> {
+    Buffer::from_iter(
+        memory_allocator,
+        BufferCreateInfo {
+            usage: BufferUsage::STORAGE_BUFFER,
+            ..Default::default()
+        },
+        AllocationCreateInfo {
+            memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+            ..Default::default()
+        },
+        palette.iter().map(MaterialGPU::from),
+    ).unwrap()
+}
+
+///what get_command_buffers draws with and into, everything but chunks and camera (those change most often)
+pub struct DrawTargets<'a> {
+    ///G-buffer pass, see get_graphical_pipeline
+    pub pipeline: &'a Arc<GraphicsPipeline>,
+    ///lighting pass, see get_lighting_pipeline
+    pub lighting_pipeline: &'a Arc<GraphicsPipeline>,
+    ///one command buffer is recorded for each, see get_framebuffers
+    pub framebuffers: &'a [Arc<Framebuffer>],
+    ///lighting pass traces shadow rays through it
+    pub volume: &'a tracer::VolumeGPU,
+}
+
+///G-buffer pass draws chunks, lighting pass shades it and traces shadow rays through volume
 pub fn get_command_buffers(
     command_buffer_allocator: &StandardCommandBufferAllocator,
+    descriptor_set_allocator: &StandardDescriptorSetAllocator,
     queue: &Arc<Queue>,
-    pipeline: &Arc<GraphicsPipeline>,
-    framebuffers: &[Arc<Framebuffer>],
-    vertex_buffer: &Subbuffer<[MyVertex]>,
+    targets: &DrawTargets,
+    chunks: &HashMap<IVec3, ChunkGPU>,
+    camera: &camera::Camera,
 ) -> Vec<Arc<PrimaryAutoCommandBuffer>> {
+    let DrawTargets {pipeline, lighting_pipeline, framebuffers, volume} = *targets;
+    let view_proj = camera.view_proj().to_cols_array_2d();
+
+    //set 0 is voxel volume (see voxel.glsl)
+    let sampler = Sampler::new(queue.device().clone(), SamplerCreateInfo::default()).unwrap();
+    let volume_set = PersistentDescriptorSet::new(
+        descriptor_set_allocator,
+        lighting_pipeline.layout().set_layouts()[0].clone(),
+        [
+            WriteDescriptorSet::buffer(0, volume.voxel_palette.clone()),
+            WriteDescriptorSet::image_view_sampler(1, volume.united_blocks_image.clone(), sampler.clone()),
+            WriteDescriptorSet::image_view_sampler(2, volume.block_palette_image.clone(), sampler),
+        ],
+        [],
+    ).unwrap();
+    let light_push_constants = LightPushConstants {
+        volume_origin: volume.origin.extend(0).to_array(),
+        volume_size: volume.size.extend(0).to_array(),
+    };
+
     framebuffers.iter()
         .map(|framebuffer| {
+            //set 1 is G-buffer of this framebuffer
+            let gbuffer_set = PersistentDescriptorSet::new(
+                descriptor_set_allocator,
+                lighting_pipeline.layout().set_layouts()[1].clone(),
+                [
+                    WriteDescriptorSet::image_view(0, framebuffer.attachments()[1].clone()),
+                    WriteDescriptorSet::image_view(1, framebuffer.attachments()[2].clone()),
+                ],
+                [],
+            ).unwrap();
+
             let mut builder = AutoCommandBufferBuilder::primary(
                 command_buffer_allocator,
                 queue.queue_family_index(),
@@ -210,7 +563,13 @@ pub fn get_command_buffers(
             builder
                 .begin_render_pass(
                     RenderPassBeginInfo {
-                        clear_values: vec![Some([0.0, 0.0, 1.0, 1.0].into()), Some(vulkano::format::ClearValue::Depth(1.0))],
+                        clear_values: vec![
+                            Some([0.0, 0.0, 1.0, 1.0].into()),
+                            //VoxelID 0 marks pixels nothing was drawn into
+                            Some([0.0, 0.0, 0.0, 0.0].into()),
+                            Some([0.0, 0.0, 0.0, 0.0].into()),
+                            Some(vulkano::format::ClearValue::Depth(1.0)),
+                        ],
                         ..RenderPassBeginInfo::framebuffer(framebuffer.clone())
                     },
                     SubpassBeginInfo {
@@ -220,10 +579,37 @@ pub fn get_command_buffers(
                 )
                 .unwrap()
                 .bind_pipeline_graphics(pipeline.clone())
+                .unwrap();
+
+            for chunk in chunks.values() {
+                builder
+                    .push_constants(pipeline.layout().clone(), 0, PushConstants {trans: chunk.trans.to_cols_array_2d(), view_proj})
+                    .unwrap();
+                match &chunk.vertices {
+                    ChunkVertices::Full(vertices) => builder.bind_vertex_buffers(0, vertices.clone()),
+                    ChunkVertices::Packed(vertices) => builder.bind_vertex_buffers(0, vertices.clone()),
+                }
+                    .unwrap();
+                builder
+                    .bind_index_buffer(chunk.indices.clone())
+                    .unwrap()
+                    .draw_indexed(chunk.indices.len() as u32, 1, 0, 0, 0)
+                    .unwrap();
+            }
+
+            builder
+                .next_subpass(Default::default(), SubpassBeginInfo {
+                    contents: SubpassContents::Inline,
+                    ..Default::default()
+                })
+                .unwrap()
+                .bind_pipeline_graphics(lighting_pipeline.clone())
                 .unwrap()
-                .bind_vertex_buffers(0, vertex_buffer.clone())
+                .bind_descriptor_sets(PipelineBindPoint::Graphics, lighting_pipeline.layout().clone(), 0, (volume_set.clone(), gbuffer_set))
                 .unwrap()
-                .draw(vertex_buffer.len() as u32, 1, 0, 0)
+                .push_constants(lighting_pipeline.layout().clone(), 0, light_push_constants)
+                .unwrap()
+                .draw(3, 1, 0, 0)
                 .unwrap()
                 .end_render_pass(Default::default())
                 .unwrap();
@@ -231,19 +617,150 @@ pub fn get_command_buffers(
             builder.build().unwrap()
         }).collect()
 }
+
+pub fn upload_vertices(
+    memory_allocator: Arc<dyn MemoryAllocator>,
+    command_buffer_allocator: &StandardCommandBufferAllocator,
+    queue: &Arc<Queue>,
+    vertices: &[MyVertex],
+) -> Subbuffer<[MyVertex]> {
+    upload_device_local(memory_allocator, command_buffer_allocator, queue, vertices, BufferUsage::VERTEX_BUFFER)
+}
+
+pub fn upload_indices(
+    memory_allocator: Arc<dyn MemoryAllocator>,
+    command_buffer_allocator: &StandardCommandBufferAllocator,
+    queue: &Arc<Queue>,
+    indices: &[u32],
+) -> Subbuffer<[u32]> {
+    upload_device_local(memory_allocator, command_buffer_allocator, queue, indices, BufferUsage::INDEX_BUFFER)
+}
+
+///device local vertex and index buffers of one mesh
+pub type MeshBuffers = (Subbuffer<[MyVertex]>, Subbuffer<[u32]>);
+
+///ogt simple mesh of voxels (order X -> Y -> Z, any size) streamed straight into host-visible staging buffers,
+/// then copied into device local ones. Neither ogt::Mesh nor Vec of the whole mesh is ever made, which matters for big volumes
+/// None if there is nothing to draw
+pub fn upload_streamed(
+    memory_allocator: Arc<dyn MemoryAllocator>,
+    command_buffer_allocator: &StandardCommandBufferAllocator,
+    queue: &Arc<Queue>,
+    voxels: &[world::VoxelID],
+    size: [u32; 3],
+) -> Result<Option<MeshBuffers>, ogt::MeshError> {
+    let (vertex_count, index_count) = mesher::stream_capacity(voxels, size)?;
+    if index_count == 0 {
+        return Ok(None);
+    }
+    let vertices = staging_buffer::<MyVertex>(memory_allocator.clone(), vertex_count);
+    let indices = staging_buffer::<u32>(memory_allocator.clone(), index_count);
+    mesher::stream_simple(voxels, size, &mut vertices.write().unwrap(), &mut indices.write().unwrap())?;
+    Ok(Some((
+        copy_to_device_local(memory_allocator.clone(), command_buffer_allocator, queue, vertices, BufferUsage::VERTEX_BUFFER),
+        copy_to_device_local(memory_allocator, command_buffer_allocator, queue, indices, BufferUsage::INDEX_BUFFER),
+    )))
+}
+
+///host-visible buffer of len elements for filling from CPU and copying from
+fn staging_buffer<T: BufferContents>(memory_allocator: Arc<dyn MemoryAllocator>, len: usize) -> Subbuffer<[T]> {
+    Buffer::new_slice::<T>(
+        memory_allocator,
+        BufferCreateInfo {
+            usage: BufferUsage::TRANSFER_SRC,
+            ..Default::default()
+        },
+        AllocationCreateInfo {
+            memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+            ..Default::default()
+        },
+        len as DeviceSize,
+    ).unwrap()
+}
+
+///copies data into new device local buffer through staging one and waits for it
+fn upload_device_local<T: BufferContents + Copy>(
+    memory_allocator: Arc<dyn MemoryAllocator>,
+    command_buffer_allocator: &StandardCommandBufferAllocator,
+    queue: &Arc<Queue>,
+    data: &[T],
+    usage: BufferUsage,
+) -> Subbuffer<[T]> {
+    let vertex_buffer = staging_buffer::<T>(memory_allocator.clone(), data.len());
+    vertex_buffer.write().unwrap().copy_from_slice(data);
+    copy_to_device_local(memory_allocator, command_buffer_allocator, queue, vertex_buffer, usage)
+}
+
+///new device local buffer with contents of staging one, waits for the copy
+fn copy_to_device_local<T: BufferContents>(
+    memory_allocator: Arc<dyn MemoryAllocator>,
+    command_buffer_allocator: &StandardCommandBufferAllocator,
+    queue: &Arc<Queue>,
+    vertex_buffer: Subbuffer<[T]>,
+    usage: BufferUsage,
+) -> Subbuffer<[T]> {
+    let local_vertex_buffer = Buffer::new_slice::<T>(
+        memory_allocator.clone(), 
+        BufferCreateInfo {
+            usage: usage | BufferUsage::TRANSFER_DST,
+            ..Default::default()
+        },
+        AllocationCreateInfo {
+            memory_type_filter: MemoryTypeFilter::PREFER_DEVICE,
+            ..Default::default()
+        },
+        vertex_buffer.len()
+    ).unwrap();
+
+    // Create a one-time command to copy between the buffers.
+    let mut cbb = AutoCommandBufferBuilder::primary(
+        command_buffer_allocator,
+        queue.queue_family_index(),
+        CommandBufferUsage::OneTimeSubmit,
+    )
+    .unwrap();
+    cbb.copy_buffer(CopyBufferInfo::buffers(
+            vertex_buffer,
+            local_vertex_buffer.clone(),
+        ))
+        .unwrap();
+    let one_time_cb = cbb.build().unwrap();
+
+    // Execute the copy command and wait for completion before proceeding.
+    one_time_cb.execute(queue.clone())
+        .unwrap()
+        .then_signal_fence_and_flush()
+        .unwrap()
+        .wait(None /* timeout */)
+        .unwrap();
+
+    local_vertex_buffer
+}
 // mod loader;
 // use crate::renderer;
 
 pub fn compile_shaders() {
     println!("shaders/v.vert -o vert.spv");
     Command::new("glslc").arg("shaders/v.vert").arg("-o").arg("shaders/vert.spv").output().unwrap();
+    println!("shaders/v_packed.vert -o vert_packed.spv");
+    Command::new("glslc").arg("shaders/v_packed.vert").arg("-o").arg("shaders/vert_packed.spv").output().unwrap();
     println!("shaders/v.frag -o frag.spv");
     Command::new("glslc").arg("shaders/v.frag").arg("-o").arg("shaders/frag.spv").output().unwrap();
+    println!("shaders/light.vert -o light_vert.spv");
+    Command::new("glslc").arg("shaders/light.vert").arg("-o").arg("shaders/light_vert.spv").output().unwrap();
+    println!("shaders/light.frag -o light_frag.spv");
+    Command::new("glslc").arg("shaders/light.frag").arg("-o").arg("shaders/light_frag.spv").output().unwrap();
+    println!("shaders/trace.comp -o trace.spv");
+    Command::new("glslc").arg("shaders/trace.comp").arg("-o").arg("shaders/trace.spv").output().unwrap();
 }
 pub fn create_instance(event_loop: &EventLoop<()>) -> Arc<Instance>{
+    create_instance_with_extensions(Surface::required_extensions(&event_loop))
+}
+///without window there is nothing to require except debug utils
+pub fn create_instance_with_extensions(mut required_extensions: InstanceExtensions) -> Arc<Instance>{
     let library = vulkano::VulkanLibrary::new().expect("no local Vulkan library/DLL");
 
-    let mut required_extensions = Surface::required_extensions(&event_loop);
     let mut enabled_layers: Vec<std::string::String>= vec![];
 
     // debug layers and extensions, might be setten to tell about perfomance and invalid usage
@@ -0,0 +1,1117 @@
+// Rust port of ogt_voxel_meshify (deprecated/ogt_voxel_meshify.hpp), mesh for mesh the same output as the C library:
+// same vertices in the same order, same indices. Types keep their C names so ogt code reads the same on both sides
+// every Mesh is allocated from its Context and given back on drop
+// contexts from ContextBuilder take memory from heap or arena, count it and can refuse it
+
+use std::{alloc::{self, Layout}, cell::RefCell, fmt, mem, ptr, slice};
+
+use glam::IVec2;
+
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ogt_mesh_vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ogt_mesh_rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ogt_mesh_vertex {
+    pub pos: ogt_mesh_vec3,
+    pub normal: ogt_mesh_vec3,
+    pub color: ogt_mesh_rgba,
+    pub palette_index: u32,
+}
+
+pub type Palette = [ogt_mesh_rgba; 256];
+
+#[derive(Debug)]
+pub enum MeshError {
+    ///voxels.len() is not size_x*size_y*size_z
+    SizeMismatch { expected: usize, got: usize },
+    ///context refused allocation: over its budget, arena is full or heap is out of memory
+    Refused { stats: AllocStats },
+}
+
+impl fmt::Display for MeshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshError::SizeMismatch { expected, got } => write!(f, "expected {} voxels, got {}", expected, got),
+            MeshError::Refused { stats } => write!(f, "ogt allocation refused with {} bytes in use", stats.in_use),
+        }
+    }
+}
+
+impl std::error::Error for MeshError {}
+
+///where meshes and scratch memory of ogt come from
+pub struct Context {
+    allocator: RefCell<Allocator>,
+}
+
+///what ogt allocated through one Context, in bytes it asked for (headers not included)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub in_use: usize,
+    ///highest in_use so far
+    pub peak: usize,
+    ///sum of every allocation
+    pub total: usize,
+    pub allocations: usize,
+    ///allocations that were refused, the call that made them fails
+    pub refused: usize,
+}
+
+///size of every block is kept right in front of it. Keeps blocks aligned like malloc does
+const HEADER: usize = 16;
+
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+struct ArenaChunk([u8; HEADER]);
+
+struct Allocator {
+    ///bump allocated, freeing only counts. Emptied by Context::reset_arena
+    arena: Option<(Vec<ArenaChunk>, usize)>,
+    budget: Option<usize>,
+    stats: AllocStats,
+}
+
+impl Allocator {
+    ///bytes allocation takes from arena, header and alignment included
+    fn footprint(size: usize) -> usize {
+        HEADER + size.div_ceil(HEADER) * HEADER
+    }
+
+    fn fits(&self, size: usize) -> bool {
+        let over_budget = self.budget.is_some_and(|budget| self.stats.in_use + size > budget);
+        let arena_full = self.arena.as_ref().is_some_and(|(memory, used)| used + Allocator::footprint(size) > memory.len() * HEADER);
+        !over_budget && !arena_full
+    }
+
+    fn alloc(&mut self, size: usize) -> *mut u8 {
+        if !self.fits(size) {
+            self.stats.refused += 1;
+            return ptr::null_mut();
+        }
+        let block = match &mut self.arena {
+            Some((memory, used)) => {
+                let block = unsafe { (memory.as_mut_ptr() as *mut u8).add(*used) };
+                *used += Allocator::footprint(size);
+                block
+            }
+            None => match Layout::from_size_align(HEADER + size, HEADER) {
+                Ok(layout) => unsafe { alloc::alloc(layout) },
+                Err(_) => ptr::null_mut(),
+            },
+        };
+        if block.is_null() {
+            self.stats.refused += 1;
+            return block;
+        }
+        unsafe { (block as *mut usize).write(size) };
+        self.stats.in_use += size;
+        self.stats.peak = self.stats.peak.max(self.stats.in_use);
+        self.stats.total += size;
+        self.stats.allocations += 1;
+        unsafe { block.add(HEADER) }
+    }
+
+    fn free(&mut self, ptr: *mut u8) {
+        let block = unsafe { ptr.sub(HEADER) };
+        let size = unsafe { (block as *const usize).read() };
+        self.stats.in_use -= size;
+        if self.arena.is_none() {
+            unsafe { alloc::dealloc(block, Layout::from_size_align_unchecked(HEADER + size, HEADER)) };
+        }
+    }
+}
+
+///zeroed memory from Context, 16 byte aligned, given back on drop
+/// zero bytes are not allocated at all (dangling pointer), like ogt never mallocs 0
+struct Memory<'ctx> {
+    ptr: ptr::NonNull<u8>,
+    size: usize,
+    ctx: &'ctx Context,
+}
+
+impl<'ctx> Memory<'ctx> {
+    ///len T starting at byte offset. T must be plain data (any bytes valid) and offset aligned for it
+    unsafe fn slice<T: Copy>(&self, offset: usize, len: usize) -> &[T] {
+        debug_assert!(offset + len * mem::size_of::<T>() <= self.size && offset % mem::align_of::<T>() == 0);
+        slice::from_raw_parts(self.ptr.as_ptr().add(offset) as *const T, len)
+    }
+
+    unsafe fn slice_mut<T: Copy>(&mut self, offset: usize, len: usize) -> &mut [T] {
+        debug_assert!(offset + len * mem::size_of::<T>() <= self.size && offset % mem::align_of::<T>() == 0);
+        slice::from_raw_parts_mut(self.ptr.as_ptr().add(offset) as *mut T, len)
+    }
+}
+
+impl<'ctx> Drop for Memory<'ctx> {
+    fn drop(&mut self) {
+        if self.size > 0 {
+            self.ctx.allocator.borrow_mut().free(self.ptr.as_ptr());
+        }
+    }
+}
+
+///T table in Context memory, e.g. hash table of remove_duplicate_vertices
+struct Table<'ctx, T> {
+    memory: Memory<'ctx>,
+    len: usize,
+    marker: std::marker::PhantomData<T>,
+}
+
+impl<'ctx, T: Copy> Table<'ctx, T> {
+    fn get(&self) -> &[T] {
+        unsafe { self.memory.slice(0, self.len) }
+    }
+
+    fn get_mut(&mut self) -> &mut [T] {
+        unsafe { self.memory.slice_mut(0, self.len) }
+    }
+}
+
+///Context with counted allocations, see Context::stats
+/// default is global allocator without budget
+#[derive(Clone, Copy, Default)]
+pub struct ContextBuilder {
+    arena: Option<usize>,
+    budget: Option<usize>,
+}
+
+impl ContextBuilder {
+    pub fn new() -> Self {
+        ContextBuilder::default()
+    }
+
+    ///take every allocation from one block of this many bytes (16 byte header each included), allocated once
+    /// freed memory is only reused after Context::reset_arena
+    pub fn arena(mut self, bytes: usize) -> Self {
+        self.arena = Some(bytes);
+        self
+    }
+
+    ///refuse allocations that would bring bytes in use over this, calls that need them return MeshError::Refused
+    pub fn budget(mut self, bytes: usize) -> Self {
+        self.budget = Some(bytes);
+        self
+    }
+
+    pub fn build(self) -> Context {
+        Context {
+            allocator: RefCell::new(Allocator {
+                arena: self.arena.map(|bytes| (vec![ArenaChunk([0; HEADER]); bytes.div_ceil(HEADER)], 0)),
+                budget: self.budget,
+                stats: AllocStats::default(),
+            }),
+        }
+    }
+}
+
+///mesh in Context memory: room for vertices of every voxel face, indices right after them
+pub struct Mesh<'ctx> {
+    memory: Memory<'ctx>,
+    ///vertices there is room for, 6 indices per 4 of them
+    capacity: usize,
+    vertex_count: usize,
+    index_count: usize,
+}
+
+impl Context {
+    ///global allocator without budget, same as Context::builder().build()
+    pub fn new() -> Self {
+        ContextBuilder::new().build()
+    }
+
+    pub fn builder() -> ContextBuilder {
+        ContextBuilder::new()
+    }
+
+    pub fn stats(&self) -> AllocStats {
+        self.allocator.borrow().stats
+    }
+
+    ///makes whole arena free again. No mesh can be alive, &mut makes sure of that
+    pub fn reset_arena(&mut self) {
+        let allocator = self.allocator.get_mut();
+        debug_assert_eq!(allocator.stats.in_use, 0, "ogt memory outlived its meshes");
+        if let Some((_, used)) = &mut allocator.arena {
+            *used = 0;
+        }
+    }
+
+    fn alloc(&self, size: usize) -> Result<Memory<'_>, MeshError> {
+        if size == 0 {
+            return Ok(Memory { ptr: ptr::NonNull::<ArenaChunk>::dangling().cast(), size, ctx: self });
+        }
+        let ptr = self.allocator.borrow_mut().alloc(size);
+        match ptr::NonNull::new(ptr) {
+            Some(ptr) => {
+                unsafe { ptr.as_ptr().write_bytes(0, size) };
+                Ok(Memory { ptr, size, ctx: self })
+            }
+            None => Err(MeshError::Refused { stats: self.stats() }),
+        }
+    }
+
+    fn table<T: Copy>(&self, len: usize, fill: T) -> Result<Table<'_, T>, MeshError> {
+        debug_assert!(mem::align_of::<T>() <= HEADER);
+        let mut table = Table { memory: self.alloc(len * mem::size_of::<T>())?, len, marker: std::marker::PhantomData };
+        table.get_mut().fill(fill);
+        Ok(table)
+    }
+
+    ///empty mesh with room for every face simple mesher would make, which no other mesher exceeds
+    fn alloc_mesh(&self, voxels: &[u8], size: [u32; 3]) -> Result<Mesh<'_>, MeshError> {
+        let faces = face_count_simple(voxels, size)?;
+        let bytes = faces * (4 * mem::size_of::<ogt_mesh_vertex>() + 6 * mem::size_of::<u32>());
+        Ok(Mesh { memory: self.alloc(bytes)?, capacity: faces * 4, vertex_count: 0, index_count: 0 })
+    }
+
+    ///one quad per visible voxel face. voxels order is X -> Y -> Z, 0 is empty
+    pub fn mesh_simple(&self, voxels: &[u8], size: [u32; 3], palette: &Palette) -> Result<Mesh<'_>, MeshError> {
+        let mut mesh = self.alloc_mesh(voxels, size)?;
+        stream_simple(voxels, size, palette, |_, vertices, indices| mesh.push(vertices, indices))?;
+        debug_assert_eq!(mesh.vertex_count, mesh.capacity);
+        Ok(mesh)
+    }
+
+    ///merges adjacent faces of the same color into boxes. Produces t-junctions
+    pub fn mesh_greedy(&self, voxels: &[u8], size: [u32; 3], palette: &Palette) -> Result<Mesh<'_>, MeshError> {
+        let mut mesh = self.alloc_mesh(voxels, size)?;
+        for pass in passes(size) {
+            greedy_pass(voxels, palette, &pass, &mut mesh)?;
+        }
+        Ok(mesh)
+    }
+
+    ///polygonizes and triangulates connected faces of the same color
+    pub fn mesh_polygon(&self, voxels: &[u8], size: [u32; 3], palette: &Palette) -> Result<Mesh<'_>, MeshError> {
+        let mut mesh = self.alloc_mesh(voxels, size)?;
+        for pass in passes(size) {
+            polygon_pass(voxels, palette, &pass, &mut mesh)?;
+        }
+        Ok(mesh)
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Context::new()
+    }
+}
+
+fn check_size(voxels: &[u8], size: [u32; 3]) -> Result<(), MeshError> {
+    let expected = size[0] as usize * size[1] as usize * size[2] as usize;
+    if voxels.len() != expected {
+        return Err(MeshError::SizeMismatch { expected, got: voxels.len() });
+    }
+    Ok(())
+}
+
+///solid voxels of volume with which of their faces (-X, +X, -Y, +Y, -Z, +Z) are visible, in memory order
+fn visible_faces(voxels: &[u8], size: [u32; 3]) -> impl Iterator<Item = ([u32; 3], u8, [bool; 6])> + '_ {
+    let [size_x, size_y, size_z] = size.map(|size| size as usize);
+    let (stride_y, stride_z) = (size_x, size_x * size_y);
+    voxels.iter().enumerate().filter(|(_, voxel)| **voxel != 0).map(move |(index, voxel)| {
+        let (i, j, k) = (index % size_x, index / size_x % size_y, index / stride_z);
+        let faces = [
+            i == 0 || voxels[index - 1] == 0,
+            i == size_x - 1 || voxels[index + 1] == 0,
+            j == 0 || voxels[index - stride_y] == 0,
+            j == size_y - 1 || voxels[index + stride_y] == 0,
+            k == 0 || voxels[index - stride_z] == 0,
+            k == size_z - 1 || voxels[index + stride_z] == 0,
+        ];
+        ([i as u32, j as u32, k as u32], *voxel, faces)
+    })
+}
+
+///quads mesh_simple and stream_simple produce, 4 vertices and 6 indices each. For preallocating stream_simple output
+pub fn face_count_simple(voxels: &[u8], size: [u32; 3]) -> Result<usize, MeshError> {
+    check_size(voxels, size)?;
+    Ok(visible_faces(voxels, size).map(|(_, _, faces)| faces.iter().filter(|face| **face).count()).sum())
+}
+
+///normal, corners (offsets from voxel min) and whether winding is 2,1,0,0,3,2 instead of 0,1,2,2,3,0
+/// of every face simple mesher makes, in the order it makes them
+const SIMPLE_FACES: [([f32; 3], [[f32; 3]; 4], bool); 6] = [
+    ([-1.0, 0.0, 0.0], [[0.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 1.0, 1.0], [0.0, 0.0, 1.0]], true),
+    ([1.0, 0.0, 0.0], [[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [1.0, 1.0, 1.0], [1.0, 0.0, 1.0]], false),
+    ([0.0, -1.0, 0.0], [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 0.0, 1.0], [0.0, 0.0, 1.0]], false),
+    ([0.0, 1.0, 0.0], [[0.0, 1.0, 0.0], [1.0, 1.0, 0.0], [1.0, 1.0, 1.0], [0.0, 1.0, 1.0]], true),
+    ([0.0, 0.0, -1.0], [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]], true),
+    ([0.0, 0.0, 1.0], [[0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [1.0, 1.0, 1.0], [0.0, 1.0, 1.0]], false),
+];
+
+///what mesh_simple makes, handed to sink voxel by voxel instead of being collected into Mesh. Nothing is allocated
+/// sink gets voxel position, its vertices and their indices, which count from first vertex of whole stream
+pub fn stream_simple<F: FnMut([u32; 3], &[ogt_mesh_vertex], &[u32])>(voxels: &[u8], size: [u32; 3], palette: &Palette, mut sink: F) -> Result<(), MeshError> {
+    check_size(voxels, size)?;
+    let mut total_vertices = 0;
+    let mut vertices = [ogt_mesh_vertex::default(); 24];
+    let mut indices = [0; 36];
+    for (pos, voxel, faces) in visible_faces(voxels, size) {
+        let min = pos.map(|coord| coord as f32);
+        let mut count = 0;
+        for ((normal, corners, flipped), _) in SIMPLE_FACES.iter().zip(faces).filter(|(_, visible)| *visible) {
+            for (vertex, corner) in vertices[count * 4..count * 4 + 4].iter_mut().zip(corners) {
+                *vertex = ogt_mesh_vertex {
+                    pos: vec3(min[0] + corner[0], min[1] + corner[1], min[2] + corner[2]),
+                    normal: vec3(normal[0], normal[1], normal[2]),
+                    color: palette[voxel as usize],
+                    palette_index: voxel as u32,
+                };
+            }
+            let winding = if *flipped { [2, 1, 0, 0, 3, 2] } else { [0, 1, 2, 2, 3, 0] };
+            for (index, corner) in indices[count * 6..count * 6 + 6].iter_mut().zip(winding) {
+                *index = total_vertices + corner;
+            }
+            total_vertices += 4;
+            count += 1;
+        }
+        if count > 0 {
+            sink(pos, &vertices[..count * 4], &indices[..count * 6]);
+        }
+    }
+    Ok(())
+}
+
+fn vec3(x: f32, y: f32, z: f32) -> ogt_mesh_vec3 {
+    ogt_mesh_vec3 { x, y, z }
+}
+
+//arithmetic in the same order C does it, so results are the same to the bit
+impl ogt_mesh_vec3 {
+    fn dot(self, other: Self) -> f32 {
+        (self.x * other.x) + (self.y * other.y) + (self.z * other.z)
+    }
+
+    fn cross(self, other: Self) -> Self {
+        vec3(
+            (self.y * other.z) - (self.z * other.y),
+            (self.z * other.x) - (self.x * other.z),
+            (self.x * other.y) - (self.y * other.x),
+        )
+    }
+
+    fn add(self, other: Self) -> Self {
+        vec3(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        vec3(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+
+    fn normalize(self) -> Self {
+        let len_inv = 1.0 / (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        vec3(self.x * len_inv, self.y * len_inv, self.z * len_inv)
+    }
+
+    fn bits(self) -> [u32; 3] {
+        [self.x.to_bits(), self.y.to_bits(), self.z.to_bits()]
+    }
+}
+
+///from slice space of one face direction (faces look towards +k) into volume space
+struct Transform {
+    side: ogt_mesh_vec3,
+    up: ogt_mesh_vec3,
+    fwd: ogt_mesh_vec3,
+    origin: ogt_mesh_vec3,
+}
+
+impl Transform {
+    fn point(&self, p: ogt_mesh_vec3) -> ogt_mesh_vec3 {
+        vec3(
+            self.origin.x + (self.side.x * p.x) + (self.up.x * p.y) + (self.fwd.x * p.z),
+            self.origin.y + (self.side.y * p.x) + (self.up.y * p.y) + (self.fwd.y * p.z),
+            self.origin.z + (self.side.z * p.x) + (self.up.z * p.y) + (self.fwd.z * p.z),
+        )
+    }
+
+    fn vector(&self, v: ogt_mesh_vec3) -> ogt_mesh_vec3 {
+        vec3(
+            (self.side.x * v.x) + (self.up.x * v.y) + (self.fwd.x * v.z),
+            (self.side.y * v.x) + (self.up.y * v.y) + (self.fwd.y * v.z),
+            (self.side.z * v.x) + (self.up.z * v.y) + (self.fwd.z * v.z),
+        )
+    }
+
+    ///mirrored, triangle winding has to be reversed
+    fn is_parity_flipped(&self) -> bool {
+        self.fwd.dot(self.side.cross(self.up)) < 0.0
+    }
+}
+
+///volume seen from one face direction: size and memory strides of its i, j, k axes, first voxel at base
+struct Pass {
+    size: [i32; 3],
+    base: isize,
+    stride: [isize; 3],
+    transform: Transform,
+}
+
+impl Pass {
+    fn voxel(&self, voxels: &[u8], i: i32, j: i32, k: i32) -> u8 {
+        voxels[(self.base + i as isize * self.stride[0] + j as isize * self.stride[1] + k as isize * self.stride[2]) as usize]
+    }
+}
+
+///+Y, -Y, +X, -X, +Z, -Z, the order greedy and polygon meshers go in
+fn passes(size: [u32; 3]) -> [Pass; 6] {
+    let [x, y, z] = size.map(|size| size as i32);
+    let (stride_x, stride_y, stride_z) = (1, x as isize, x as isize * y as isize);
+    let (zero, unit_x, unit_y, unit_z) = (vec3(0.0, 0.0, 0.0), vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0));
+    let transform = |side, up, fwd, origin| Transform { side, up, fwd, origin };
+    [
+        Pass { size: [z, x, y], base: 0, stride: [stride_z, stride_x, stride_y], transform: transform(unit_z, unit_x, unit_y, zero) },
+        Pass {
+            size: [z, x, y], base: (y as isize - 1) * stride_y, stride: [stride_z, stride_x, -stride_y],
+            transform: transform(unit_z, unit_x, vec3(0.0, -1.0, 0.0), vec3(0.0, y as f32, 0.0)),
+        },
+        Pass { size: [y, z, x], base: 0, stride: [stride_y, stride_z, stride_x], transform: transform(unit_y, unit_z, unit_x, zero) },
+        Pass {
+            size: [y, z, x], base: (x as isize - 1) * stride_x, stride: [stride_y, stride_z, -stride_x],
+            transform: transform(unit_y, unit_z, vec3(-1.0, 0.0, 0.0), vec3(x as f32, 0.0, 0.0)),
+        },
+        Pass { size: [x, y, z], base: 0, stride: [stride_x, stride_y, stride_z], transform: transform(unit_x, unit_y, unit_z, zero) },
+        Pass {
+            size: [x, y, z], base: (z as isize - 1) * stride_z, stride: [stride_x, stride_y, -stride_z],
+            transform: transform(unit_x, unit_y, vec3(0.0, 0.0, -1.0), vec3(0.0, 0.0, z as f32)),
+        },
+    ]
+}
+
+///per slice: from every voxel not yet covered grows the biggest rectangle of its color along i, then along j,
+/// and makes one quad of it. Voxels covered by the next slice get no face
+fn greedy_pass(voxels: &[u8], palette: &Palette, pass: &Pass, mesh: &mut Mesh) -> Result<(), MeshError> {
+    let [size_x, size_y, size_z] = pass.size;
+    let normal = pass.transform.vector(vec3(0.0, 0.0, 1.0));
+    let winding = if pass.transform.is_parity_flipped() { [0, 3, 2, 2, 1, 0] } else { [0, 1, 2, 2, 3, 0] };
+    let at = |i: i32, j: i32| (i + j * size_x) as usize;
+    let mut polygonized = mesh.memory.ctx.table((size_x * size_y) as usize, false)?;
+    for k0 in 0..size_z {
+        let k1 = k0 + 1;
+        polygonized.get_mut().fill(false);
+        for j0 in 0..size_y {
+            for i0 in 0..size_x {
+                let color_index = pass.voxel(voxels, i0, j0, k0);
+                //voxel of the rectangle's color, not in another rectangle yet and with visible face
+                let open = |i: i32, j: i32| pass.voxel(voxels, i, j, k0) == color_index
+                    && !polygonized.get()[at(i, j)]
+                    && (k1 == size_z || pass.voxel(voxels, i, j, k1) == 0);
+                if color_index == 0 || !open(i0, j0) {
+                    continue;
+                }
+                let i1 = (i0 + 1..size_x).find(|i| !open(*i, j0)).unwrap_or(size_x);
+                let j1 = (j0 + 1..size_y).find(|j| (i0..i1).any(|i| !open(i, *j))).unwrap_or(size_y);
+                for j in j0..j1 {
+                    polygonized.get_mut()[at(i0, j)..at(i1, j)].fill(true);
+                }
+
+                let (min_x, max_x, min_y, max_y, max_z) = (i0 as f32, i1 as f32, j0 as f32, j1 as f32, k1 as f32);
+                let vertex = |x, y| ogt_mesh_vertex {
+                    pos: pass.transform.point(vec3(x, y, max_z)),
+                    normal,
+                    color: palette[color_index as usize],
+                    palette_index: color_index as u32,
+                };
+                let base = mesh.vertex_count as u32;
+                mesh.push(
+                    &[vertex(min_x, min_y), vertex(max_x, min_y), vertex(max_x, max_y), vertex(min_x, max_y)],
+                    &winding.map(|corner| base + corner),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn cardinal_unit_vector(vec: IVec2) -> IVec2 {
+    debug_assert!((vec.x == 0) != (vec.y == 0), "not a cardinal vector");
+    vec.signum()
+}
+
+fn cardinal_vector_length(vec: IVec2) -> i32 {
+    vec.x.abs() + vec.y.abs()
+}
+
+fn triangle_signed_area(v0: IVec2, v1: IVec2, v2: IVec2) -> i32 {
+    ((v0.x - v1.x) * (v2.y - v1.y)) - ((v0.y - v1.y) * (v2.x - v1.x))
+}
+
+fn is_point_in_triangle(v0: IVec2, v1: IVec2, v2: IVec2, p: IVec2) -> bool {
+    let convex_v0v1 = triangle_signed_area(v0, v1, p) >= 0;
+    let convex_v1v2 = triangle_signed_area(v1, v2, p) >= 0;
+    let convex_v2v0 = triangle_signed_area(v2, v0, p) >= 0;
+    convex_v0v1 == convex_v1v2 && convex_v0v1 == convex_v2v0
+}
+
+///ear clipping of polygon ring. Indices into verts, a malformed polygon gets only the triangles found before getting stuck
+fn tessellate_polygon(verts: &[IVec2]) -> Vec<u32> {
+    let mut ring: Vec<u32> = (0..verts.len() as u32).collect();
+    let mut indices = Vec::with_capacity((verts.len() - 2) * 3);
+    let mut no_progress = 0;
+    let mut i0 = 0;
+    while ring.len() > 3 {
+        i0 %= ring.len();
+        let i1 = (i0 + 1) % ring.len();
+        let i2 = (i0 + 2) % ring.len();
+        let (v0, v1, v2) = (verts[ring[i0] as usize], verts[ring[i1] as usize], verts[ring[i2] as usize]);
+
+        //convex and no other corner inside, corners on top of the triangle's own are fine
+        let can_triangulate = triangle_signed_area(v0, v1, v2) > 0 && ring.iter().enumerate().all(|(i, index)| {
+            let p = verts[*index as usize];
+            i == i0 || i == i1 || i == i2 || p == v0 || p == v1 || p == v2 || !is_point_in_triangle(v0, v1, v2, p)
+        });
+        if can_triangulate {
+            indices.extend_from_slice(&[ring[i2], ring[i1], ring[i0]]);
+            ring.remove(i1);
+            no_progress = 0;
+        } else {
+            no_progress += 1;
+            i0 += 1;
+        }
+        if no_progress == ring.len() {
+            return indices;
+        }
+    }
+    indices.extend_from_slice(&[ring[2], ring[1], ring[0]]);
+    indices
+}
+
+///offset from edge start to the cell on the inside of the edge
+fn edge_bias(v0: IVec2, v1: IVec2) -> IVec2 {
+    if v0.x < v1.x {
+        IVec2::new(0, 0)
+    } else if v0.x > v1.x {
+        IVec2::new(-1, -1)
+    } else if v0.y < v1.y {
+        IVec2::new(-1, 0)
+    } else {
+        IVec2::new(0, -1)
+    }
+}
+
+///pushes a point wherever color of the cells along edge v0 -> v1 changes, so neighbouring polygons meet without t-junctions
+fn tessellate_edge(tess: &mut Vec<IVec2>, v0: IVec2, v1: IVec2, slice_colors: &[u8], size_x: i32, size_y: i32) {
+    let edge_len = cardinal_vector_length(v1 - v0);
+    let bias = edge_bias(v0, v1);
+    let step = cardinal_unit_vector(v1 - v0);
+    let mut pos = v0 + bias;
+
+    //edge on the slice boundary has no cells to look at
+    let outside = if v0.x < v1.x {
+        pos.y == size_y
+    } else if v0.x > v1.x {
+        pos.y == -1
+    } else if v0.y < v1.y {
+        pos.x == -1
+    } else {
+        pos.x == size_x
+    };
+    if outside {
+        return;
+    }
+
+    let color = |pos: IVec2| slice_colors[(pos.x + pos.y * size_x) as usize];
+    let mut last_color = color(pos);
+    pos += step;
+    for _ in 1..edge_len {
+        let curr_color = color(pos);
+        if curr_color != last_color {
+            tess.push(pos - bias);
+            last_color = curr_color;
+        }
+        pos += step;
+    }
+}
+
+///flood fills polygon of cell (i, j)'s color from that cell: pushes edges of the ring outwards one row of cells at
+/// a time while every cell they'd cover has polygon's color and isn't polygonized, until no edge moves
+/// returns ring of corners, with extra points where color outside an edge changes
+fn construct_polygon_for_slice(i: i32, j: i32, size_x: i32, size_y: i32, slice_colors: &[u8], polygonized: &mut [bool]) -> Vec<IVec2> {
+    let mut verts = vec![IVec2::new(i, j), IVec2::new(i, j + 1), IVec2::new(i + 1, j + 1), IVec2::new(i + 1, j)];
+    let polygon_color = slice_colors[(i + j * size_x) as usize];
+    polygonized[(i + j * size_x) as usize] = true;
+
+    let mut next_edge = 0;
+    let mut no_progress = 0;
+    while no_progress < verts.len() {
+        //edge0 v0 -> v1, edge1 v1 -> v2 (the one being pushed), edge2 v2 -> v3
+        let n = verts.len();
+        let v0 = if next_edge < 1 { n - 1 } else { next_edge - 1 };
+        let v1 = next_edge;
+        let v2 = if v1 < n - 1 { v1 + 1 } else { 0 };
+        let v3 = if v2 < n - 1 { v2 + 1 } else { 0 };
+
+        let edge0_unit = cardinal_unit_vector(verts[v1] - verts[v0]);
+        let edge1_unit = cardinal_unit_vector(verts[v2] - verts[v1]);
+        let edge2_unit = cardinal_unit_vector(verts[v3] - verts[v2]);
+        let edge1_normal = IVec2::new(-edge1_unit.y, edge1_unit.x);
+
+        //neighbouring edge running along the normal would have to shrink, not supported
+        let can_extrude = edge1_normal != edge0_unit * -1 && edge1_normal != edge2_unit;
+
+        let mut pushed = 0;
+        if can_extrude {
+            let edge1_len = cardinal_vector_length(verts[v2] - verts[v1]);
+            let mut origin = verts[v1] + edge_bias(verts[v1], verts[v2]);
+            while origin.x >= 0 && origin.y >= 0 && origin.x < size_x && origin.y < size_y {
+                let cells = |origin: IVec2| (0..edge1_len).map(move |index| {
+                    let cell = origin + edge1_unit * index;
+                    (cell.x + cell.y * size_x) as usize
+                });
+                if cells(origin).any(|cell| slice_colors[cell] != polygon_color || polygonized[cell]) {
+                    break;
+                }
+                for cell in cells(origin) {
+                    polygonized[cell] = true;
+                }
+                pushed += 1;
+                origin += edge1_normal;
+            }
+        }
+
+        if pushed == 0 {
+            next_edge = (next_edge + 1) % n;
+            no_progress += 1;
+            continue;
+        }
+
+        //replace v0, v1, v2, v3 with their tessellations
+        let (cached_v0, cached_v1, cached_v2, cached_v3) = (verts[v0], verts[v1], verts[v2], verts[v3]);
+        let extruded_v1 = cached_v1 + edge1_normal * pushed;
+        let extruded_v2 = cached_v2 + edge1_normal * pushed;
+        //edge1 continues edge0 (or edge2) in the same direction, otherwise it extends it
+        let is_e0e1_extrude = edge0_unit == edge1_unit;
+        let is_e1e2_extrude = edge1_unit == edge2_unit;
+
+        let mut tess_e0 = vec![cached_v0];
+        if is_e0e1_extrude {
+            tess_e0.push(cached_v1);
+        } else {
+            tessellate_edge(&mut tess_e0, cached_v0, extruded_v1, slice_colors, size_x, size_y);
+        }
+        let mut tess_e1 = Vec::new();
+        if is_e0e1_extrude {
+            tessellate_edge(&mut tess_e1, cached_v1, extruded_v1, slice_colors, size_x, size_y);
+        }
+        tess_e1.push(extruded_v1);
+        tessellate_edge(&mut tess_e1, extruded_v1, extruded_v2, slice_colors, size_x, size_y);
+        tess_e1.push(extruded_v2);
+        if is_e1e2_extrude {
+            tessellate_edge(&mut tess_e1, extruded_v2, cached_v2, slice_colors, size_x, size_y);
+        }
+        let mut tess_e2 = Vec::new();
+        if is_e1e2_extrude {
+            tess_e2.push(cached_v2);
+        } else {
+            tessellate_edge(&mut tess_e2, extruded_v2, cached_v3, slice_colors, size_x, size_y);
+        }
+        let tess_e3 = [cached_v3];
+
+        //rest of the ring, from after v3 round to before v0. Where the four land depends on where they straddle ring end
+        let others: Vec<IVec2> = (0..n - 4).map(|index| verts[(v3 + 1 + index) % n]).collect();
+        let mut ring = Vec::with_capacity(n - 4 + tess_e0.len() + tess_e1.len() + tess_e2.len() + 1);
+        if v1 < v0 {
+            //[v1][v2][v3] (others) [v0]
+            next_edge = 0;
+            ring.extend(tess_e1.iter().chain(&tess_e2).chain(&tess_e3).chain(&others).chain(&tess_e0));
+        } else if v2 < v0 {
+            //[v2][v3] (others) [v0][v1]
+            next_edge = tess_e2.len() + tess_e3.len() + others.len() + tess_e0.len();
+            ring.extend(tess_e2.iter().chain(&tess_e3).chain(&others).chain(&tess_e0).chain(&tess_e1));
+        } else if v3 < v0 {
+            //[v3] (others) [v0][v1][v2]
+            next_edge = tess_e3.len() + others.len() + tess_e0.len();
+            ring.extend(tess_e3.iter().chain(&others).chain(&tess_e0).chain(&tess_e1).chain(&tess_e2));
+        } else {
+            //(others before v0) [v0][v1][v2][v3] (others after v3)
+            next_edge = v0 + tess_e0.len();
+            ring.extend(verts[..v0].iter().chain(&tess_e0).chain(&tess_e1).chain(&tess_e2).chain(&tess_e3).chain(&verts[v3 + 1..]));
+        }
+        verts = ring;
+        no_progress = 0;
+    }
+    verts
+}
+
+///per slice: floods polygons of one color over the voxels with visible face and triangulates them
+/// cells covered by the next slice take its color, so polygon edges get points where the next slice's do
+fn polygon_pass(voxels: &[u8], palette: &Palette, pass: &Pass, mesh: &mut Mesh) -> Result<(), MeshError> {
+    let [size_x, size_y, size_z] = pass.size;
+    let normal = pass.transform.vector(vec3(0.0, 0.0, 1.0));
+    let is_parity_flipped = pass.transform.is_parity_flipped();
+    let ctx = mesh.memory.ctx;
+    let mut polygonized = ctx.table((size_x * size_y) as usize, false)?;
+    let mut slice_colors = ctx.table((size_x * size_y) as usize, 0u8)?;
+    let (polygonized, slice_colors) = (polygonized.get_mut(), slice_colors.get_mut());
+    for k in 0..size_z {
+        polygonized.fill(false);
+        for j in 0..size_y {
+            for i in 0..size_x {
+                let index = (i + j * size_x) as usize;
+                let next_color = if k < size_z - 1 { pass.voxel(voxels, i, j, k + 1) } else { 0 };
+                slice_colors[index] = if next_color != 0 { next_color } else { pass.voxel(voxels, i, j, k) };
+                polygonized[index] = next_color != 0;
+            }
+        }
+
+        for j in 0..size_y {
+            for i in 0..size_x {
+                let index = (i + j * size_x) as usize;
+                let color_index = slice_colors[index];
+                if color_index == 0 || polygonized[index] {
+                    continue;
+                }
+                let verts = construct_polygon_for_slice(i, j, size_x, size_y, slice_colors, polygonized);
+                let vertices: Vec<ogt_mesh_vertex> = verts.iter().map(|vert| ogt_mesh_vertex {
+                    pos: pass.transform.point(vec3(vert.x as f32, vert.y as f32, (k + 1) as f32)),
+                    normal,
+                    color: palette[color_index as usize],
+                    palette_index: color_index as u32,
+                }).collect();
+                let base = mesh.vertex_count as u32;
+                let mut indices = tessellate_polygon(&verts);
+                for triangle in indices.chunks_exact_mut(3) {
+                    if is_parity_flipped {
+                        triangle.swap(0, 2);
+                    }
+                    for index in triangle {
+                        *index += base;
+                    }
+                }
+                mesh.push(&vertices, &indices);
+            }
+        }
+    }
+    Ok(())
+}
+
+///murmur hash 2 of 4 byte words, what ogt buckets vertices with
+fn murmur_hash2(mut h: u32, data: &[u32]) -> u32 {
+    const M: u32 = 0x5bd1e995;
+    for k in data {
+        let mut k = k.wrapping_mul(M);
+        k ^= k >> 24;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M);
+        h ^= k;
+    }
+    h
+}
+
+///numbers every distinct key in order of first appearance: remap[vertex] is number of its key
+/// table is hash table (power of 2 long, at least as long as remap) of first vertex with each key, quadratic probing
+fn remap_unique<const N: usize>(table: &mut [u32], remap: &mut [u32], key: impl Fn(usize) -> [u32; N]) -> usize {
+    table.fill(u32::MAX);
+    let mask = table.len() - 1;
+    let mut unique = 0;
+    for vertex in 0..remap.len() {
+        let this = key(vertex);
+        let mut bucket = murmur_hash2(0, &this) as usize & mask;
+        for probe in 0..=mask {
+            let existing = table[bucket];
+            if existing == u32::MAX {
+                table[bucket] = vertex as u32;
+                remap[vertex] = unique;
+                unique += 1;
+                break;
+            }
+            if key(existing as usize) == this {
+                remap[vertex] = remap[existing as usize];
+                break;
+            }
+            bucket = (bucket + probe + 1) & mask;
+        }
+    }
+    unique as usize
+}
+
+fn vertex_words(vertex: &ogt_mesh_vertex) -> [u32; 8] {
+    let [px, py, pz] = vertex.pos.bits();
+    let [nx, ny, nz] = vertex.normal.bits();
+    let color = vertex.color;
+    [px, py, pz, nx, ny, nz, u32::from_le_bytes([color.r, color.g, color.b, color.a]), vertex.palette_index]
+}
+
+impl<'ctx> Mesh<'ctx> {
+    fn index_offset(&self) -> usize {
+        self.capacity * mem::size_of::<ogt_mesh_vertex>()
+    }
+
+    pub fn vertices(&self) -> &[ogt_mesh_vertex] {
+        unsafe { self.memory.slice(0, self.vertex_count) }
+    }
+
+    pub fn indices(&self) -> &[u32] {
+        unsafe { self.memory.slice(self.index_offset(), self.index_count) }
+    }
+
+    fn vertices_mut(&mut self) -> &mut [ogt_mesh_vertex] {
+        unsafe { self.memory.slice_mut(0, self.vertex_count) }
+    }
+
+    fn indices_mut(&mut self) -> &mut [u32] {
+        let offset = self.index_offset();
+        unsafe { self.memory.slice_mut(offset, self.index_count) }
+    }
+
+    fn push(&mut self, vertices: &[ogt_mesh_vertex], indices: &[u32]) {
+        assert!(self.vertex_count + vertices.len() <= self.capacity && (self.index_count + indices.len()) * 4 <= self.capacity * 6, "ogt mesh overflow");
+        self.vertex_count += vertices.len();
+        self.index_count += indices.len();
+        let (vertex_count, index_count) = (self.vertex_count, self.index_count);
+        self.vertices_mut()[vertex_count - vertices.len()..].copy_from_slice(vertices);
+        self.indices_mut()[index_count - indices.len()..].copy_from_slice(indices);
+    }
+
+    ///in-place removes identical vertices and remaps indices
+    pub fn remove_duplicate_vertices(&mut self) -> Result<(), MeshError> {
+        let ctx = self.memory.ctx;
+        let mut table = ctx.table(self.vertex_count.next_power_of_two(), u32::MAX)?;
+        let mut remap = ctx.table(self.vertex_count, u32::MAX)?;
+        let vertices = self.vertices();
+        let unique = remap_unique(table.get_mut(), remap.get_mut(), |vertex| vertex_words(&vertices[vertex]));
+
+        //duplicate only ever moves onto earlier copy of itself, so compacting in place is safe
+        let vertices = self.vertices_mut();
+        for (src, dst) in remap.get().iter().enumerate() {
+            vertices[*dst as usize] = vertices[src];
+        }
+        for index in self.indices_mut() {
+            *index = remap.get()[*index as usize];
+        }
+        self.vertex_count = unique;
+        Ok(())
+    }
+
+    ///averages normals of adjacent faces. Meant for mesh_simple output
+    pub fn smooth_normals(&mut self) -> Result<(), MeshError> {
+        let ctx = self.memory.ctx;
+        let mut remap = ctx.table(self.vertex_count, u32::MAX)?;
+        {
+            let mut table = ctx.table(self.vertex_count.next_power_of_two(), u32::MAX)?;
+            let vertices = self.vertices();
+            remap_unique(table.get_mut(), remap.get_mut(), |vertex| vertices[vertex].pos.bits());
+        }
+        let mut normals = ctx.table(self.vertex_count, vec3(0.0, 0.0, 0.0))?;
+
+        //area weighted normals of every triangle, summed per position
+        let (vertices, remap) = (self.vertices(), remap.get());
+        for triangle in self.indices().chunks_exact(3) {
+            let [v0, v1, v2] = [0, 1, 2].map(|corner| vertices[triangle[corner] as usize].pos);
+            let normal = v1.sub(v0).cross(v2.sub(v0));
+            for corner in triangle {
+                let normals = normals.get_mut();
+                let sum = &mut normals[remap[*corner as usize] as usize];
+                *sum = sum.add(normal);
+            }
+        }
+        let vertices = unsafe { self.memory.slice_mut::<ogt_mesh_vertex>(0, self.vertex_count) };
+        for (vertex, position) in vertices.iter_mut().zip(remap) {
+            let normal = normals.get()[*position as usize];
+            if normal.dot(normal) > 0.001 {
+                vertex.normal = normal.normalize();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PALETTE: Palette = [ogt_mesh_rgba { r: 255, g: 255, b: 255, a: 255 }; 256];
+
+    ///3x3x3 with voxel in the middle, a cube of 6 quads
+    fn lone_voxel() -> Vec<u8> {
+        let mut voxels = vec![0; 27];
+        voxels[13] = 1;
+        voxels
+    }
+
+    #[test]
+    fn rust_allocations_are_counted_and_freed() {
+        let ctx = Context::builder().build();
+        {
+            let mut mesh = ctx.mesh_simple(&lone_voxel(), [3; 3], &PALETTE).unwrap();
+            assert_eq!(mesh.indices().len(), 36);
+            mesh.remove_duplicate_vertices().unwrap();
+            assert!(ctx.stats().in_use > 0);
+        }
+        let stats = ctx.stats();
+        assert_eq!(stats.in_use, 0);
+        assert!(stats.peak > 0 && stats.total >= stats.peak && stats.allocations >= 3);
+        assert_eq!(stats.refused, 0);
+    }
+
+    #[test]
+    fn budget_refuses_mesh_and_scratch() {
+        let ctx = Context::builder().budget(64).build();
+        assert!(matches!(ctx.mesh_simple(&lone_voxel(), [3; 3], &PALETTE), Err(MeshError::Refused { .. })));
+
+        //exactly enough for the mesh, nothing left for remove_duplicate_vertices
+        let mesh_bytes = {
+            let ctx = Context::builder().build();
+            let _mesh = ctx.mesh_simple(&lone_voxel(), [3; 3], &PALETTE).unwrap();
+            ctx.stats().in_use
+        };
+        let ctx = Context::builder().budget(mesh_bytes).build();
+        let mut mesh = ctx.mesh_simple(&lone_voxel(), [3; 3], &PALETTE).unwrap();
+        assert!(matches!(mesh.remove_duplicate_vertices(), Err(MeshError::Refused { .. })));
+        assert_eq!(mesh.vertices().len(), 24, "refused call must leave mesh alone");
+        drop(mesh);
+
+        //greedy and polygon meshes are the same size, their slice scratch does not fit next to them
+        assert!(matches!(ctx.mesh_greedy(&lone_voxel(), [3; 3], &PALETTE), Err(MeshError::Refused { .. })));
+        assert!(matches!(ctx.mesh_polygon(&lone_voxel(), [3; 3], &PALETTE), Err(MeshError::Refused { .. })));
+        assert_eq!(ctx.stats().in_use, 0, "refused mesh gives its memory back");
+        let ctx = Context::builder().budget(mesh_bytes * 2).build();
+        assert!(ctx.mesh_greedy(&lone_voxel(), [3; 3], &PALETTE).is_ok());
+        assert!(ctx.mesh_polygon(&lone_voxel(), [3; 3], &PALETTE).is_ok());
+    }
+
+    #[test]
+    fn stream_matches_mesh_simple() {
+        let mut voxels = lone_voxel();
+        voxels[14] = 2;
+        let ctx = Context::new();
+        let mesh = ctx.mesh_simple(&voxels, [3; 3], &PALETTE).unwrap();
+        assert_eq!(face_count_simple(&voxels, [3; 3]).unwrap() * 6, mesh.indices().len());
+
+        let (mut vertices, mut indices, mut calls) = (Vec::new(), Vec::new(), 0);
+        stream_simple(&voxels, [3; 3], &PALETTE, |pos, v, i| {
+            assert_eq!(pos[1..], [1, 1]);
+            vertices.extend_from_slice(v);
+            indices.extend_from_slice(i);
+            calls += 1;
+        }).unwrap();
+        assert_eq!(calls, 2);
+        assert_eq!(indices, mesh.indices());
+        assert!(vertices.iter().zip(mesh.vertices()).all(|(a, b)| (a.pos.x, a.pos.y, a.pos.z, a.palette_index) == (b.pos.x, b.pos.y, b.pos.z, b.palette_index)));
+    }
+
+    #[test]
+    fn arena_is_reused_after_reset() {
+        let mut ctx = Context::builder().arena(64 * 1024).build();
+        for _ in 0..100 {
+            let mut mesh = ctx.mesh_simple(&lone_voxel(), [3; 3], &PALETTE).unwrap();
+            mesh.remove_duplicate_vertices().unwrap();
+            drop(mesh);
+            ctx.reset_arena();
+        }
+        assert_eq!(ctx.stats().refused, 0);
+        assert_eq!(ctx.stats().allocations, 300);
+
+        let small = Context::builder().arena(64).build();
+        assert!(matches!(small.mesh_simple(&lone_voxel(), [3; 3], &PALETTE), Err(MeshError::Refused { .. })));
+    }
+
+    ///palette with different color for every index, so colors are checked too
+    fn rainbow() -> Palette {
+        let mut palette = PALETTE;
+        for (i, color) in palette.iter_mut().enumerate() {
+            *color = ogt_mesh_rgba { r: i as u8, g: 255 - i as u8, b: (i * 7) as u8, a: 255 };
+        }
+        palette
+    }
+
+    ///volumes assets/ogt_reference was recorded from, with the C ogt_voxel_meshify this module replaced
+    /// deprecated/ogt_reference.cpp builds the same volumes and writes those files, change both together
+    fn reference_volumes() -> Vec<(String, Vec<u8>, [u32; 3])> {
+        let mut volumes = vec![("lone".to_string(), lone_voxel(), [3; 3])];
+
+        //deterministic noise, about 40% solid in 4 colors
+        let size = [7, 6, 5];
+        let mut state = 12345u32;
+        let noise = (0..7 * 6 * 5).map(|_| {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            let r = state >> 24;
+            if r < 102 { (r % 4 + 1) as u8 } else { 0 }
+        }).collect();
+        volumes.push(("noise".to_string(), noise, size));
+
+        //colored stripes with holes, makes concave polygons with holes touching edges
+        let size = [12, 11, 4];
+        let slab = (0..12 * 11 * 4).map(|i| {
+            let (x, y, z) = (i % 12, i / 12 % 11, i / (12 * 11));
+            let hole = (x % 7 == 3 && y % 5 != 0) || (x + y) % 9 == 0 && z > 0;
+            if z > 2 || hole { 0 } else { (1 + (x / 5 + y / 6) % 3) as u8 }
+        }).collect();
+        volumes.push(("slab".to_string(), slab, size));
+
+        let scene = crate::loader::load_vox("assets/scene.vox").unwrap();
+        for (i, model) in scene.models.iter().enumerate() {
+            let size = [model.size.x, model.size.y, model.size.z];
+            let mut voxels = vec![0; (size[0] * size[1] * size[2]) as usize];
+            for voxel in &model.voxels {
+                voxels[(voxel.x as u32 + voxel.y as u32 * size[0] + voxel.z as u32 * size[0] * size[1]) as usize] = voxel.i.wrapping_add(1).max(1);
+            }
+            volumes.push((format!("scene{}", i), voxels, size));
+        }
+        volumes
+    }
+
+    ///mesh file: vertex and index count, then vertices and indices as they are in memory (little endian)
+    fn mesh_bytes(mesh: &Mesh) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(mesh.vertices().len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(mesh.indices().len() as u32).to_le_bytes());
+        for vertex in mesh.vertices() {
+            for float in [vertex.pos.x, vertex.pos.y, vertex.pos.z, vertex.normal.x, vertex.normal.y, vertex.normal.z] {
+                bytes.extend_from_slice(&float.to_le_bytes());
+            }
+            bytes.extend_from_slice(&[vertex.color.r, vertex.color.g, vertex.color.b, vertex.color.a]);
+            bytes.extend_from_slice(&vertex.palette_index.to_le_bytes());
+        }
+        for index in mesh.indices() {
+            bytes.extend_from_slice(&index.to_le_bytes());
+        }
+        bytes
+    }
+
+    ///every mesher, then remove_duplicate_vertices, then smooth_normals on top
+    fn reference_meshes(voxels: &[u8], size: [u32; 3]) -> Vec<(String, Vec<u8>)> {
+        let ctx = Context::new();
+        let palette = rainbow();
+        let mut meshes = Vec::new();
+        for (name, mesh) in [("simple", ctx.mesh_simple(voxels, size, &palette)), ("greedy", ctx.mesh_greedy(voxels, size, &palette)), ("polygon", ctx.mesh_polygon(voxels, size, &palette))] {
+            let mut mesh = mesh.unwrap();
+            meshes.push((name.to_string(), mesh_bytes(&mesh)));
+            mesh.remove_duplicate_vertices().unwrap();
+            meshes.push((format!("{}_dedup", name), mesh_bytes(&mesh)));
+            mesh.smooth_normals().unwrap();
+            meshes.push((format!("{}_smooth", name), mesh_bytes(&mesh)));
+        }
+        meshes
+    }
+
+    #[test]
+    fn meshes_match_recorded_reference() {
+        for (volume, voxels, size) in reference_volumes() {
+            for (name, bytes) in reference_meshes(&voxels, size) {
+                let expected = std::fs::read(format!("assets/ogt_reference/{}_{}.bin", volume, name)).unwrap();
+                assert_eq!(bytes[..8], expected[..8], "{} {}: vertex and index count", volume, name);
+                assert!(bytes == expected, "{} {} differs from reference", volume, name);
+            }
+        }
+    }
+}
@@ -1,3876 +0,0 @@
-/* automatically generated by rust-bindgen 0.69.4 */
-
-pub const _GLIBCXX_CXX_CONFIG_H: u32 = 1;
-pub const _GLIBCXX_RELEASE: u32 = 13;
-pub const __GLIBCXX__: u32 = 20230727;
-pub const _GLIBCXX_HAVE_ATTRIBUTE_VISIBILITY: u32 = 0;
-pub const _GLIBCXX_USE_DEPRECATED: u32 = 1;
-pub const _GLIBCXX_EXTERN_TEMPLATE: u32 = 1;
-pub const _GLIBCXX_USE_DUAL_ABI: u32 = 1;
-pub const _GLIBCXX_USE_CXX11_ABI: u32 = 1;
-pub const _GLIBCXX_INLINE_VERSION: u32 = 0;
-pub const _GLIBCXX_HAVE_IS_CONSTANT_EVALUATED: u32 = 1;
-pub const _GLIBCXX_VERBOSE_ASSERT: u32 = 1;
-pub const _GLIBCXX_USE_ALLOCATOR_NEW: u32 = 1;
-pub const __GTHREAD_HIDE_WIN32API: u32 = 1;
-pub const NOMINMAX: u32 = 1;
-pub const __USE_MINGW_ANSI_STDIO: u32 = 1;
-pub const _GLIBCXX_HAVE_DOS_BASED_FILESYSTEM: u32 = 1;
-pub const _GLIBCXX_NO_IOCTL: u32 = 1;
-pub const _GLIBCXX_LLP64: u32 = 1;
-pub const _GLIBCXX_USE_WEAK_REF: u32 = 0;
-pub const _GLIBCXX_THREAD_ATEXIT_WIN32: u32 = 1;
-pub const _GLIBCXX_USE_GET_NPROCS_WIN32: u32 = 1;
-pub const _GTHREAD_USE_MUTEX_INIT_FUNC: u32 = 1;
-pub const _GLIBCXX_USE_CRT_RAND_S: u32 = 1;
-pub const _GLIBCXX_BUFSIZ: u32 = 4096;
-pub const _GLIBCXX_CPU_DEFINES: u32 = 1;
-pub const _GLIBCXX_USE_STD_SPEC_FUNCS: u32 = 1;
-pub const _GLIBCXX_FAST_MATH: u32 = 0;
-pub const _GLIBCXX_USE_FLOAT128: u32 = 1;
-pub const _GLIBCXX_FLOAT_IS_IEEE_BINARY32: u32 = 1;
-pub const _GLIBCXX_DOUBLE_IS_IEEE_BINARY64: u32 = 1;
-pub const _GLIBCXX_HAVE_BUILTIN_HAS_UNIQ_OBJ_REP: u32 = 1;
-pub const _GLIBCXX_HAVE_BUILTIN_IS_AGGREGATE: u32 = 1;
-pub const _GLIBCXX_HAVE_BUILTIN_IS_SAME: u32 = 1;
-pub const _GLIBCXX_HAVE_BUILTIN_LAUNDER: u32 = 1;
-pub const _PSTL_VERSION: u32 = 12000;
-pub const _PSTL_VERSION_MAJOR: u32 = 12;
-pub const _PSTL_VERSION_MINOR: u32 = 0;
-pub const _PSTL_VERSION_PATCH: u32 = 0;
-pub const _PSTL_USAGE_WARNINGS: u32 = 0;
-pub const _PSTL_UDR_PRESENT: u32 = 0;
-pub const _PSTL_PRAGMA_LOCATION: &[u8; 26] = b" [Parallel STL message]: \0";
-pub const _GLIBCXX_HAVE_ACOSF: u32 = 1;
-pub const _GLIBCXX_HAVE_ACOSL: u32 = 1;
-pub const _GLIBCXX_HAVE_ASINF: u32 = 1;
-pub const _GLIBCXX_HAVE_ASINL: u32 = 1;
-pub const _GLIBCXX_HAVE_ATAN2F: u32 = 1;
-pub const _GLIBCXX_HAVE_ATAN2L: u32 = 1;
-pub const _GLIBCXX_HAVE_ATANF: u32 = 1;
-pub const _GLIBCXX_HAVE_ATANL: u32 = 1;
-pub const _GLIBCXX_HAVE_ATOMIC_LOCK_POLICY: u32 = 1;
-pub const _GLIBCXX_HAVE_CEILF: u32 = 1;
-pub const _GLIBCXX_HAVE_CEILL: u32 = 1;
-pub const _GLIBCXX_HAVE_COMPLEX_H: u32 = 1;
-pub const _GLIBCXX_HAVE_COSF: u32 = 1;
-pub const _GLIBCXX_HAVE_COSHF: u32 = 1;
-pub const _GLIBCXX_HAVE_COSHL: u32 = 1;
-pub const _GLIBCXX_HAVE_COSL: u32 = 1;
-pub const _GLIBCXX_HAVE_DECL_STRNLEN: u32 = 1;
-pub const _GLIBCXX_HAVE_DIRENT_H: u32 = 1;
-pub const _GLIBCXX_HAVE_EXPF: u32 = 1;
-pub const _GLIBCXX_HAVE_EXPL: u32 = 1;
-pub const _GLIBCXX_HAVE_FABSF: u32 = 1;
-pub const _GLIBCXX_HAVE_FABSL: u32 = 1;
-pub const _GLIBCXX_HAVE_FCNTL_H: u32 = 1;
-pub const _GLIBCXX_HAVE_FENV_H: u32 = 1;
-pub const _GLIBCXX_HAVE_FINITE: u32 = 1;
-pub const _GLIBCXX_HAVE_FLOAT_H: u32 = 1;
-pub const _GLIBCXX_HAVE_FLOORF: u32 = 1;
-pub const _GLIBCXX_HAVE_FLOORL: u32 = 1;
-pub const _GLIBCXX_HAVE_FMODF: u32 = 1;
-pub const _GLIBCXX_HAVE_FMODL: u32 = 1;
-pub const _GLIBCXX_HAVE_FPCLASS: u32 = 1;
-pub const _GLIBCXX_HAVE_FREXPF: u32 = 1;
-pub const _GLIBCXX_HAVE_FREXPL: u32 = 1;
-pub const _GLIBCXX_HAVE_GETIPINFO: u32 = 1;
-pub const _GLIBCXX_HAVE_GETS: u32 = 1;
-pub const _GLIBCXX_HAVE_HYPOT: u32 = 1;
-pub const _GLIBCXX_HAVE_HYPOTF: u32 = 1;
-pub const _GLIBCXX_HAVE_HYPOTL: u32 = 1;
-pub const _GLIBCXX_HAVE_ICONV: u32 = 1;
-pub const _GLIBCXX_HAVE_IEEEFP_H: u32 = 1;
-pub const _GLIBCXX_HAVE_INTTYPES_H: u32 = 1;
-pub const _GLIBCXX_HAVE_ISNAN: u32 = 1;
-pub const _GLIBCXX_HAVE_ISWBLANK: u32 = 1;
-pub const _GLIBCXX_HAVE_LDEXPF: u32 = 1;
-pub const _GLIBCXX_HAVE_LDEXPL: u32 = 1;
-pub const _GLIBCXX_HAVE_LOCALE_H: u32 = 1;
-pub const _GLIBCXX_HAVE_LOG10F: u32 = 1;
-pub const _GLIBCXX_HAVE_LOG10L: u32 = 1;
-pub const _GLIBCXX_HAVE_LOGF: u32 = 1;
-pub const _GLIBCXX_HAVE_LOGL: u32 = 1;
-pub const _GLIBCXX_HAVE_MBSTATE_T: u32 = 1;
-pub const _GLIBCXX_HAVE_MEMORY_H: u32 = 1;
-pub const _GLIBCXX_HAVE_MODF: u32 = 1;
-pub const _GLIBCXX_HAVE_MODFF: u32 = 1;
-pub const _GLIBCXX_HAVE_MODFL: u32 = 1;
-pub const _GLIBCXX_HAVE_POSIX_SEMAPHORE: u32 = 1;
-pub const _GLIBCXX_HAVE_POWF: u32 = 1;
-pub const _GLIBCXX_HAVE_POWL: u32 = 1;
-pub const _GLIBCXX_HAVE_SINCOS: u32 = 1;
-pub const _GLIBCXX_HAVE_SINCOSF: u32 = 1;
-pub const _GLIBCXX_HAVE_SINCOSL: u32 = 1;
-pub const _GLIBCXX_HAVE_SINF: u32 = 1;
-pub const _GLIBCXX_HAVE_SINHF: u32 = 1;
-pub const _GLIBCXX_HAVE_SINHL: u32 = 1;
-pub const _GLIBCXX_HAVE_SINL: u32 = 1;
-pub const _GLIBCXX_HAVE_SQRTF: u32 = 1;
-pub const _GLIBCXX_HAVE_SQRTL: u32 = 1;
-pub const _GLIBCXX_HAVE_STDALIGN_H: u32 = 1;
-pub const _GLIBCXX_HAVE_STDBOOL_H: u32 = 1;
-pub const _GLIBCXX_HAVE_STDINT_H: u32 = 1;
-pub const _GLIBCXX_HAVE_STDLIB_H: u32 = 1;
-pub const _GLIBCXX_HAVE_STRINGS_H: u32 = 1;
-pub const _GLIBCXX_HAVE_STRING_H: u32 = 1;
-pub const _GLIBCXX_HAVE_STRTOF: u32 = 1;
-pub const _GLIBCXX_HAVE_STRTOLD: u32 = 1;
-pub const _GLIBCXX_HAVE_SYS_PARAM_H: u32 = 1;
-pub const _GLIBCXX_HAVE_SYS_STAT_H: u32 = 1;
-pub const _GLIBCXX_HAVE_SYS_TIME_H: u32 = 1;
-pub const _GLIBCXX_HAVE_SYS_TYPES_H: u32 = 1;
-pub const _GLIBCXX_HAVE_S_ISREG: u32 = 1;
-pub const _GLIBCXX_HAVE_TANF: u32 = 1;
-pub const _GLIBCXX_HAVE_TANHF: u32 = 1;
-pub const _GLIBCXX_HAVE_TANHL: u32 = 1;
-pub const _GLIBCXX_HAVE_TANL: u32 = 1;
-pub const _GLIBCXX_HAVE_TGMATH_H: u32 = 1;
-pub const _GLIBCXX_HAVE_TLS: u32 = 1;
-pub const _GLIBCXX_HAVE_TRUNCATE: u32 = 1;
-pub const _GLIBCXX_HAVE_UCHAR_H: u32 = 1;
-pub const _GLIBCXX_HAVE_UNISTD_H: u32 = 1;
-pub const _GLIBCXX_HAVE_UTIME_H: u32 = 1;
-pub const _GLIBCXX_HAVE_VFWSCANF: u32 = 1;
-pub const _GLIBCXX_HAVE_VSWSCANF: u32 = 1;
-pub const _GLIBCXX_HAVE_VWSCANF: u32 = 1;
-pub const _GLIBCXX_HAVE_WCHAR_H: u32 = 1;
-pub const _GLIBCXX_HAVE_WCSTOF: u32 = 1;
-pub const _GLIBCXX_HAVE_WCTYPE_H: u32 = 1;
-pub const _GLIBCXX_HAVE_WINDOWS_H: u32 = 1;
-pub const _GLIBCXX_HAVE__ALIGNED_MALLOC: u32 = 1;
-pub const _GLIBCXX_HAVE__FINITEF: u32 = 1;
-pub const _GLIBCXX_HAVE__ISNANF: u32 = 1;
-pub const _GLIBCXX_HAVE__WFOPEN: u32 = 1;
-pub const _GLIBCXX_HAVE___CXA_THREAD_ATEXIT: u32 = 1;
-pub const _GLIBCXX_LT_OBJDIR: &[u8; 7] = b".libs/\0";
-pub const _GLIBCXX_PACKAGE_BUGREPORT: &[u8; 1] = b"\0";
-pub const _GLIBCXX_PACKAGE_NAME: &[u8; 15] = b"package-unused\0";
-pub const _GLIBCXX_PACKAGE_STRING: &[u8; 30] = b"package-unused version-unused\0";
-pub const _GLIBCXX_PACKAGE_TARNAME: &[u8; 10] = b"libstdc++\0";
-pub const _GLIBCXX_PACKAGE_URL: &[u8; 1] = b"\0";
-pub const _GLIBCXX_PACKAGE__GLIBCXX_VERSION: &[u8; 15] = b"version-unused\0";
-pub const _GLIBCXX_STDC_HEADERS: u32 = 1;
-pub const _GLIBCXX_DARWIN_USE_64_BIT_INODE: u32 = 1;
-pub const _GLIBCXX_FILE_OFFSET_BITS: u32 = 64;
-pub const _GLIBCXX11_USE_C99_COMPLEX: u32 = 1;
-pub const _GLIBCXX11_USE_C99_MATH: u32 = 1;
-pub const _GLIBCXX11_USE_C99_STDIO: u32 = 1;
-pub const _GLIBCXX11_USE_C99_STDLIB: u32 = 1;
-pub const _GLIBCXX11_USE_C99_WCHAR: u32 = 1;
-pub const _GLIBCXX98_USE_C99_COMPLEX: u32 = 1;
-pub const _GLIBCXX98_USE_C99_MATH: u32 = 1;
-pub const _GLIBCXX98_USE_C99_STDIO: u32 = 1;
-pub const _GLIBCXX98_USE_C99_STDLIB: u32 = 1;
-pub const _GLIBCXX98_USE_C99_WCHAR: u32 = 1;
-pub const _GLIBCXX_ATOMIC_BUILTINS: u32 = 1;
-pub const _GLIBCXX_CAN_ALIGNAS_DESTRUCTIVE_SIZE: u32 = 1;
-pub const _GLIBCXX_FULLY_DYNAMIC_STRING: u32 = 1;
-pub const _GLIBCXX_HAS_GTHREADS: u32 = 1;
-pub const _GLIBCXX_STATIC_TZDATA: u32 = 1;
-pub const _GLIBCXX_STDIO_EOF: i32 = -1;
-pub const _GLIBCXX_STDIO_SEEK_CUR: u32 = 1;
-pub const _GLIBCXX_STDIO_SEEK_END: u32 = 2;
-pub const _GLIBCXX_USE_C99: u32 = 1;
-pub const _GLIBCXX_USE_C99_COMPLEX_TR1: u32 = 1;
-pub const _GLIBCXX_USE_C99_CTYPE_TR1: u32 = 1;
-pub const _GLIBCXX_USE_C99_FENV_TR1: u32 = 1;
-pub const _GLIBCXX_USE_C99_INTTYPES_TR1: u32 = 1;
-pub const _GLIBCXX_USE_C99_INTTYPES_WCHAR_T_TR1: u32 = 1;
-pub const _GLIBCXX_USE_C99_MATH_TR1: u32 = 1;
-pub const _GLIBCXX_USE_C99_STDINT_TR1: u32 = 1;
-pub const _GLIBCXX_USE_CLOCK_MONOTONIC: u32 = 1;
-pub const _GLIBCXX_USE_CLOCK_REALTIME: u32 = 1;
-pub const _GLIBCXX_USE_DECIMAL_FLOAT: u32 = 1;
-pub const _GLIBCXX_USE_FSEEKO_FTELLO: u32 = 1;
-pub const _GLIBCXX_USE_GETTIMEOFDAY: u32 = 1;
-pub const _GLIBCXX_USE_INIT_PRIORITY_ATTRIBUTE: u32 = 1;
-pub const _GLIBCXX_USE_LFS: u32 = 1;
-pub const _GLIBCXX_USE_LONG_LONG: u32 = 1;
-pub const _GLIBCXX_USE_NANOSLEEP: u32 = 1;
-pub const _GLIBCXX_USE_PTHREADS_NUM_PROCESSORS_NP: u32 = 1;
-pub const _GLIBCXX_USE_PTHREAD_RWLOCK_T: u32 = 1;
-pub const _GLIBCXX_USE_SCHED_YIELD: u32 = 1;
-pub const _GLIBCXX_USE_TMPNAM: u32 = 1;
-pub const _GLIBCXX_USE_UTIME: u32 = 1;
-pub const _GLIBCXX_USE_WCHAR_T: u32 = 1;
-pub const _GLIBCXX_VERBOSE: u32 = 1;
-pub const _GLIBCXX_X86_RDRAND: u32 = 1;
-pub const _GLIBCXX_X86_RDSEED: u32 = 1;
-pub const _GTHREAD_USE_MUTEX_TIMEDLOCK: u32 = 1;
-pub const _GLIBCXX_HAVE_FINITEF: u32 = 1;
-pub const _GLIBCXX_HAVE_ISNANF: u32 = 1;
-pub const __MINGW64_VERSION_MAJOR: u32 = 12;
-pub const __MINGW64_VERSION_MINOR: u32 = 0;
-pub const __MINGW64_VERSION_BUGFIX: u32 = 0;
-pub const __MINGW64_VERSION_RC: u32 = 0;
-pub const __MINGW64_VERSION_STATE: &[u8; 6] = b"alpha\0";
-pub const __MINGW32_MAJOR_VERSION: u32 = 3;
-pub const __MINGW32_MINOR_VERSION: u32 = 11;
-pub const _M_AMD64: u32 = 100;
-pub const _M_X64: u32 = 100;
-pub const __: u32 = 1;
-pub const __MINGW_USE_UNDERSCORE_PREFIX: u32 = 0;
-pub const __MINGW_HAVE_ANSI_C99_PRINTF: u32 = 1;
-pub const __MINGW_HAVE_WIDE_C99_PRINTF: u32 = 1;
-pub const __MINGW_HAVE_ANSI_C99_SCANF: u32 = 1;
-pub const __MINGW_HAVE_WIDE_C99_SCANF: u32 = 1;
-pub const __MINGW_SEC_WARN_STR : & [u8 ; 92] = b"This function or variable may be unsafe, use _CRT_SECURE_NO_WARNINGS to disable deprecation\0" ;
-pub const __MINGW_MSVC2005_DEPREC_STR : & [u8 ; 117] = b"This POSIX function is deprecated beginning in Visual C++ 2005, use _CRT_NONSTDC_NO_DEPRECATE to disable deprecation\0" ;
-pub const __MINGW_FORTIFY_LEVEL: u32 = 0;
-pub const __MINGW_FORTIFY_VA_ARG: u32 = 0;
-pub const _CRT_SECURE_CPP_OVERLOAD_SECURE_NAMES: u32 = 1;
-pub const _CRT_SECURE_CPP_OVERLOAD_SECURE_NAMES_MEMORY: u32 = 0;
-pub const _CRT_SECURE_CPP_OVERLOAD_STANDARD_NAMES: u32 = 0;
-pub const _CRT_SECURE_CPP_OVERLOAD_STANDARD_NAMES_COUNT: u32 = 0;
-pub const _CRT_SECURE_CPP_OVERLOAD_STANDARD_NAMES_MEMORY: u32 = 0;
-pub const __USE_CRTIMP: u32 = 1;
-pub const USE___UUIDOF: u32 = 0;
-pub const __CRT__NO_INLINE: u32 = 1;
-pub const __MSVCRT_VERSION__: u32 = 1792;
-pub const _WIN32_WINNT: u32 = 1537;
-pub const MINGW_HAS_SECURE_API: u32 = 1;
-pub const __STDC_SECURE_LIB__: u32 = 200411;
-pub const __GOT_SECURE_LIB__: u32 = 200411;
-pub const MINGW_HAS_DDK_H: u32 = 1;
-pub const _CRT_PACKING: u32 = 8;
-pub const _SECURECRT_FILL_BUFFER_PATTERN: u32 = 253;
-pub const _ARGMAX: u32 = 100;
-pub const _NLSCMPERROR: u32 = 2147483647;
-pub const NULL: u32 = 0;
-pub const _GLIBCXX_CSTRING: u32 = 1;
-pub const INT8_MIN: i32 = -128;
-pub const INT16_MIN: i32 = -32768;
-pub const INT32_MIN: i32 = -2147483648;
-pub const INT64_MIN: i64 = -9223372036854775808;
-pub const INT8_MAX: u32 = 127;
-pub const INT16_MAX: u32 = 32767;
-pub const INT32_MAX: u32 = 2147483647;
-pub const INT64_MAX: u64 = 9223372036854775807;
-pub const UINT8_MAX: u32 = 255;
-pub const UINT16_MAX: u32 = 65535;
-pub const UINT32_MAX: u32 = 4294967295;
-pub const UINT64_MAX: i32 = -1;
-pub const INT_LEAST8_MIN: i32 = -128;
-pub const INT_LEAST16_MIN: i32 = -32768;
-pub const INT_LEAST32_MIN: i32 = -2147483648;
-pub const INT_LEAST64_MIN: i64 = -9223372036854775808;
-pub const INT_LEAST8_MAX: u32 = 127;
-pub const INT_LEAST16_MAX: u32 = 32767;
-pub const INT_LEAST32_MAX: u32 = 2147483647;
-pub const INT_LEAST64_MAX: u64 = 9223372036854775807;
-pub const UINT_LEAST8_MAX: u32 = 255;
-pub const UINT_LEAST16_MAX: u32 = 65535;
-pub const UINT_LEAST32_MAX: u32 = 4294967295;
-pub const UINT_LEAST64_MAX: i32 = -1;
-pub const INT_FAST8_MIN: i32 = -128;
-pub const INT_FAST16_MIN: i32 = -32768;
-pub const INT_FAST32_MIN: i32 = -2147483648;
-pub const INT_FAST64_MIN: i64 = -9223372036854775808;
-pub const INT_FAST8_MAX: u32 = 127;
-pub const INT_FAST16_MAX: u32 = 32767;
-pub const INT_FAST32_MAX: u32 = 2147483647;
-pub const INT_FAST64_MAX: u64 = 9223372036854775807;
-pub const UINT_FAST8_MAX: u32 = 255;
-pub const UINT_FAST16_MAX: u32 = 65535;
-pub const UINT_FAST32_MAX: u32 = 4294967295;
-pub const UINT_FAST64_MAX: i32 = -1;
-pub const INTPTR_MIN: i64 = -9223372036854775808;
-pub const INTPTR_MAX: u64 = 9223372036854775807;
-pub const UINTPTR_MAX: i32 = -1;
-pub const INTMAX_MIN: i64 = -9223372036854775808;
-pub const INTMAX_MAX: u64 = 9223372036854775807;
-pub const UINTMAX_MAX: i32 = -1;
-pub const PTRDIFF_MIN: i64 = -9223372036854775808;
-pub const PTRDIFF_MAX: u64 = 9223372036854775807;
-pub const SIG_ATOMIC_MIN: i32 = -2147483648;
-pub const SIG_ATOMIC_MAX: u32 = 2147483647;
-pub const SIZE_MAX: i32 = -1;
-pub const WCHAR_MIN: u32 = 0;
-pub const WCHAR_MAX: u32 = 65535;
-pub const WINT_MIN: u32 = 0;
-pub const WINT_MAX: u32 = 65535;
-pub const PRId64: &[u8; 4] = b"lld\0";
-pub const PRIi64: &[u8; 4] = b"lli\0";
-pub const PRIo64: &[u8; 4] = b"llo\0";
-pub const PRIu64: &[u8; 4] = b"llu\0";
-pub const PRIx64: &[u8; 4] = b"llx\0";
-pub const PRIX64: &[u8; 4] = b"llX\0";
-pub const PRId8: &[u8; 2] = b"d\0";
-pub const PRId16: &[u8; 2] = b"d\0";
-pub const PRId32: &[u8; 2] = b"d\0";
-pub const PRIdLEAST8: &[u8; 2] = b"d\0";
-pub const PRIdLEAST16: &[u8; 2] = b"d\0";
-pub const PRIdLEAST32: &[u8; 2] = b"d\0";
-pub const PRIdLEAST64: &[u8; 4] = b"lld\0";
-pub const PRIdFAST8: &[u8; 2] = b"d\0";
-pub const PRIdFAST16: &[u8; 2] = b"d\0";
-pub const PRIdFAST32: &[u8; 2] = b"d\0";
-pub const PRIdFAST64: &[u8; 4] = b"lld\0";
-pub const PRIdMAX: &[u8; 4] = b"lld\0";
-pub const PRIi8: &[u8; 2] = b"i\0";
-pub const PRIi16: &[u8; 2] = b"i\0";
-pub const PRIi32: &[u8; 2] = b"i\0";
-pub const PRIiLEAST8: &[u8; 2] = b"i\0";
-pub const PRIiLEAST16: &[u8; 2] = b"i\0";
-pub const PRIiLEAST32: &[u8; 2] = b"i\0";
-pub const PRIiLEAST64: &[u8; 4] = b"lli\0";
-pub const PRIiFAST8: &[u8; 2] = b"i\0";
-pub const PRIiFAST16: &[u8; 2] = b"i\0";
-pub const PRIiFAST32: &[u8; 2] = b"i\0";
-pub const PRIiFAST64: &[u8; 4] = b"lli\0";
-pub const PRIiMAX: &[u8; 4] = b"lli\0";
-pub const PRIo8: &[u8; 2] = b"o\0";
-pub const PRIo16: &[u8; 2] = b"o\0";
-pub const PRIo32: &[u8; 2] = b"o\0";
-pub const PRIoLEAST8: &[u8; 2] = b"o\0";
-pub const PRIoLEAST16: &[u8; 2] = b"o\0";
-pub const PRIoLEAST32: &[u8; 2] = b"o\0";
-pub const PRIoLEAST64: &[u8; 4] = b"llo\0";
-pub const PRIoFAST8: &[u8; 2] = b"o\0";
-pub const PRIoFAST16: &[u8; 2] = b"o\0";
-pub const PRIoFAST32: &[u8; 2] = b"o\0";
-pub const PRIoFAST64: &[u8; 4] = b"llo\0";
-pub const PRIoMAX: &[u8; 4] = b"llo\0";
-pub const PRIu8: &[u8; 2] = b"u\0";
-pub const PRIu16: &[u8; 2] = b"u\0";
-pub const PRIu32: &[u8; 2] = b"u\0";
-pub const PRIuLEAST8: &[u8; 2] = b"u\0";
-pub const PRIuLEAST16: &[u8; 2] = b"u\0";
-pub const PRIuLEAST32: &[u8; 2] = b"u\0";
-pub const PRIuLEAST64: &[u8; 4] = b"llu\0";
-pub const PRIuFAST8: &[u8; 2] = b"u\0";
-pub const PRIuFAST16: &[u8; 2] = b"u\0";
-pub const PRIuFAST32: &[u8; 2] = b"u\0";
-pub const PRIuFAST64: &[u8; 4] = b"llu\0";
-pub const PRIuMAX: &[u8; 4] = b"llu\0";
-pub const PRIx8: &[u8; 2] = b"x\0";
-pub const PRIx16: &[u8; 2] = b"x\0";
-pub const PRIx32: &[u8; 2] = b"x\0";
-pub const PRIxLEAST8: &[u8; 2] = b"x\0";
-pub const PRIxLEAST16: &[u8; 2] = b"x\0";
-pub const PRIxLEAST32: &[u8; 2] = b"x\0";
-pub const PRIxLEAST64: &[u8; 4] = b"llx\0";
-pub const PRIxFAST8: &[u8; 2] = b"x\0";
-pub const PRIxFAST16: &[u8; 2] = b"x\0";
-pub const PRIxFAST32: &[u8; 2] = b"x\0";
-pub const PRIxFAST64: &[u8; 4] = b"llx\0";
-pub const PRIxMAX: &[u8; 4] = b"llx\0";
-pub const PRIX8: &[u8; 2] = b"X\0";
-pub const PRIX16: &[u8; 2] = b"X\0";
-pub const PRIX32: &[u8; 2] = b"X\0";
-pub const PRIXLEAST8: &[u8; 2] = b"X\0";
-pub const PRIXLEAST16: &[u8; 2] = b"X\0";
-pub const PRIXLEAST32: &[u8; 2] = b"X\0";
-pub const PRIXLEAST64: &[u8; 4] = b"llX\0";
-pub const PRIXFAST8: &[u8; 2] = b"X\0";
-pub const PRIXFAST16: &[u8; 2] = b"X\0";
-pub const PRIXFAST32: &[u8; 2] = b"X\0";
-pub const PRIXFAST64: &[u8; 4] = b"llX\0";
-pub const PRIXMAX: &[u8; 4] = b"llX\0";
-pub const SCNd16: &[u8; 3] = b"hd\0";
-pub const SCNd32: &[u8; 2] = b"d\0";
-pub const SCNd64: &[u8; 4] = b"lld\0";
-pub const SCNdLEAST16: &[u8; 3] = b"hd\0";
-pub const SCNdLEAST32: &[u8; 2] = b"d\0";
-pub const SCNdLEAST64: &[u8; 4] = b"lld\0";
-pub const SCNdFAST16: &[u8; 3] = b"hd\0";
-pub const SCNdFAST32: &[u8; 2] = b"d\0";
-pub const SCNdFAST64: &[u8; 4] = b"lld\0";
-pub const SCNdMAX: &[u8; 4] = b"lld\0";
-pub const SCNi16: &[u8; 3] = b"hi\0";
-pub const SCNi32: &[u8; 2] = b"i\0";
-pub const SCNi64: &[u8; 4] = b"lli\0";
-pub const SCNiLEAST16: &[u8; 3] = b"hi\0";
-pub const SCNiLEAST32: &[u8; 2] = b"i\0";
-pub const SCNiLEAST64: &[u8; 4] = b"lli\0";
-pub const SCNiFAST16: &[u8; 3] = b"hi\0";
-pub const SCNiFAST32: &[u8; 2] = b"i\0";
-pub const SCNiFAST64: &[u8; 4] = b"lli\0";
-pub const SCNiMAX: &[u8; 4] = b"lli\0";
-pub const SCNo16: &[u8; 3] = b"ho\0";
-pub const SCNo32: &[u8; 2] = b"o\0";
-pub const SCNo64: &[u8; 4] = b"llo\0";
-pub const SCNoLEAST16: &[u8; 3] = b"ho\0";
-pub const SCNoLEAST32: &[u8; 2] = b"o\0";
-pub const SCNoLEAST64: &[u8; 4] = b"llo\0";
-pub const SCNoFAST16: &[u8; 3] = b"ho\0";
-pub const SCNoFAST32: &[u8; 2] = b"o\0";
-pub const SCNoFAST64: &[u8; 4] = b"llo\0";
-pub const SCNoMAX: &[u8; 4] = b"llo\0";
-pub const SCNx16: &[u8; 3] = b"hx\0";
-pub const SCNx32: &[u8; 2] = b"x\0";
-pub const SCNx64: &[u8; 4] = b"llx\0";
-pub const SCNxLEAST16: &[u8; 3] = b"hx\0";
-pub const SCNxLEAST32: &[u8; 2] = b"x\0";
-pub const SCNxLEAST64: &[u8; 4] = b"llx\0";
-pub const SCNxFAST16: &[u8; 3] = b"hx\0";
-pub const SCNxFAST32: &[u8; 2] = b"x\0";
-pub const SCNxFAST64: &[u8; 4] = b"llx\0";
-pub const SCNxMAX: &[u8; 4] = b"llx\0";
-pub const SCNu16: &[u8; 3] = b"hu\0";
-pub const SCNu32: &[u8; 2] = b"u\0";
-pub const SCNu64: &[u8; 4] = b"llu\0";
-pub const SCNuLEAST16: &[u8; 3] = b"hu\0";
-pub const SCNuLEAST32: &[u8; 2] = b"u\0";
-pub const SCNuLEAST64: &[u8; 4] = b"llu\0";
-pub const SCNuFAST16: &[u8; 3] = b"hu\0";
-pub const SCNuFAST32: &[u8; 2] = b"u\0";
-pub const SCNuFAST64: &[u8; 4] = b"llu\0";
-pub const SCNuMAX: &[u8; 4] = b"llu\0";
-pub const PRIdPTR: &[u8; 4] = b"lld\0";
-pub const PRIiPTR: &[u8; 4] = b"lli\0";
-pub const PRIoPTR: &[u8; 4] = b"llo\0";
-pub const PRIuPTR: &[u8; 4] = b"llu\0";
-pub const PRIxPTR: &[u8; 4] = b"llx\0";
-pub const PRIXPTR: &[u8; 4] = b"llX\0";
-pub const SCNdPTR: &[u8; 4] = b"lld\0";
-pub const SCNiPTR: &[u8; 4] = b"lli\0";
-pub const SCNoPTR: &[u8; 4] = b"llo\0";
-pub const SCNxPTR: &[u8; 4] = b"llx\0";
-pub const SCNuPTR: &[u8; 4] = b"llu\0";
-pub const SCNd8: &[u8; 4] = b"hhd\0";
-pub const SCNdLEAST8: &[u8; 4] = b"hhd\0";
-pub const SCNdFAST8: &[u8; 4] = b"hhd\0";
-pub const SCNi8: &[u8; 4] = b"hhi\0";
-pub const SCNiLEAST8: &[u8; 4] = b"hhi\0";
-pub const SCNiFAST8: &[u8; 4] = b"hhi\0";
-pub const SCNo8: &[u8; 4] = b"hho\0";
-pub const SCNoLEAST8: &[u8; 4] = b"hho\0";
-pub const SCNoFAST8: &[u8; 4] = b"hho\0";
-pub const SCNx8: &[u8; 4] = b"hhx\0";
-pub const SCNxLEAST8: &[u8; 4] = b"hhx\0";
-pub const SCNxFAST8: &[u8; 4] = b"hhx\0";
-pub const SCNu8: &[u8; 4] = b"hhu\0";
-pub const SCNuLEAST8: &[u8; 4] = b"hhu\0";
-pub const SCNuFAST8: &[u8; 4] = b"hhu\0";
-pub const _GLIBCXX_STDLIB_H: u32 = 1;
-pub const _GLIBCXX_CSTDLIB: u32 = 1;
-pub const PATH_MAX: u32 = 260;
-pub const CHAR_BIT: u32 = 8;
-pub const SCHAR_MIN: i32 = -128;
-pub const SCHAR_MAX: u32 = 127;
-pub const UCHAR_MAX: u32 = 255;
-pub const CHAR_MIN: i32 = -128;
-pub const CHAR_MAX: u32 = 127;
-pub const MB_LEN_MAX: u32 = 5;
-pub const SHRT_MIN: i32 = -32768;
-pub const SHRT_MAX: u32 = 32767;
-pub const USHRT_MAX: u32 = 65535;
-pub const INT_MIN: i32 = -2147483648;
-pub const INT_MAX: u32 = 2147483647;
-pub const UINT_MAX: u32 = 4294967295;
-pub const LONG_MIN: i32 = -2147483648;
-pub const LONG_MAX: u32 = 2147483647;
-pub const ULONG_MAX: u32 = 4294967295;
-pub const LLONG_MAX: u64 = 9223372036854775807;
-pub const LLONG_MIN: i64 = -9223372036854775808;
-pub const ULLONG_MAX: i32 = -1;
-pub const _I8_MIN: i32 = -128;
-pub const _I8_MAX: u32 = 127;
-pub const _UI8_MAX: u32 = 255;
-pub const _I16_MIN: i32 = -32768;
-pub const _I16_MAX: u32 = 32767;
-pub const _UI16_MAX: u32 = 65535;
-pub const _I32_MIN: i32 = -2147483648;
-pub const _I32_MAX: u32 = 2147483647;
-pub const _UI32_MAX: u32 = 4294967295;
-pub const LONG_LONG_MAX: u64 = 9223372036854775807;
-pub const LONG_LONG_MIN: i64 = -9223372036854775808;
-pub const ULONG_LONG_MAX: i32 = -1;
-pub const _I64_MIN: i64 = -9223372036854775808;
-pub const _I64_MAX: u64 = 9223372036854775807;
-pub const _UI64_MAX: i32 = -1;
-pub const SSIZE_MAX: u64 = 9223372036854775807;
-pub const __USE_MINGW_STRTOX: u32 = 1;
-pub const EXIT_SUCCESS: u32 = 0;
-pub const EXIT_FAILURE: u32 = 1;
-pub const RAND_MAX: u32 = 32767;
-pub const _MAX_PATH: u32 = 260;
-pub const _MAX_DRIVE: u32 = 3;
-pub const _MAX_DIR: u32 = 256;
-pub const _MAX_FNAME: u32 = 256;
-pub const _MAX_EXT: u32 = 256;
-pub const _OUT_TO_DEFAULT: u32 = 0;
-pub const _OUT_TO_STDERR: u32 = 1;
-pub const _OUT_TO_MSGBOX: u32 = 2;
-pub const _REPORT_ERRMODE: u32 = 3;
-pub const _WRITE_ABORT_MSG: u32 = 1;
-pub const _CALL_REPORTFAULT: u32 = 2;
-pub const _MAX_ENV: u32 = 32767;
-pub const _CVTBUFSIZE: u32 = 349;
-pub const _HEAP_MAXREQ: i32 = -32;
-pub const _HEAPEMPTY: i32 = -1;
-pub const _HEAPOK: i32 = -2;
-pub const _HEAPBADBEGIN: i32 = -3;
-pub const _HEAPBADNODE: i32 = -4;
-pub const _HEAPEND: i32 = -5;
-pub const _HEAPBADPTR: i32 = -6;
-pub const _FREEENTRY: u32 = 0;
-pub const _USEDENTRY: u32 = 1;
-pub const _MAX_WAIT_MALLOC_CRT: u32 = 60000;
-pub const _ALLOCA_S_THRESHOLD: u32 = 1024;
-pub const _ALLOCA_S_STACK_MARKER: u32 = 52428;
-pub const _ALLOCA_S_HEAP_MARKER: u32 = 56797;
-pub const _ALLOCA_S_MARKER_SIZE: u32 = 16;
-pub type std_nullptr_t = *const ::std::os::raw::c_void;
-pub type __gnuc_va_list = __builtin_va_list;
-pub type va_list = __gnuc_va_list;
-extern "C" {
-    pub fn __mingw_get_crt_info() -> *const ::std::os::raw::c_char;
-}
-pub type rsize_t = usize;
-pub type wint_t = ::std::os::raw::c_ushort;
-pub type wctype_t = ::std::os::raw::c_ushort;
-pub type errno_t = ::std::os::raw::c_int;
-pub type __time32_t = ::std::os::raw::c_long;
-pub type __time64_t = ::std::os::raw::c_longlong;
-pub type time_t = __time64_t;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct threadmbcinfostruct {
-    _unused: [u8; 0],
-}
-pub type pthreadlocinfo = *mut threadlocaleinfostruct;
-pub type pthreadmbcinfo = *mut threadmbcinfostruct;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct __lc_time_data {
-    _unused: [u8; 0],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct localeinfo_struct {
-    pub locinfo: pthreadlocinfo,
-    pub mbcinfo: pthreadmbcinfo,
-}
-#[test]
-fn bindgen_test_layout_localeinfo_struct() {
-    const UNINIT: ::std::mem::MaybeUninit<localeinfo_struct> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<localeinfo_struct>(),
-        16usize,
-        concat!("Size of: ", stringify!(localeinfo_struct))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<localeinfo_struct>(),
-        8usize,
-        concat!("Alignment of ", stringify!(localeinfo_struct))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).locinfo) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(localeinfo_struct),
-            "::",
-            stringify!(locinfo)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).mbcinfo) as usize - ptr as usize },
-        8usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(localeinfo_struct),
-            "::",
-            stringify!(mbcinfo)
-        )
-    );
-}
-pub type _locale_tstruct = localeinfo_struct;
-pub type _locale_t = *mut localeinfo_struct;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct tagLC_ID {
-    pub wLanguage: ::std::os::raw::c_ushort,
-    pub wCountry: ::std::os::raw::c_ushort,
-    pub wCodePage: ::std::os::raw::c_ushort,
-}
-#[test]
-fn bindgen_test_layout_tagLC_ID() {
-    const UNINIT: ::std::mem::MaybeUninit<tagLC_ID> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<tagLC_ID>(),
-        6usize,
-        concat!("Size of: ", stringify!(tagLC_ID))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<tagLC_ID>(),
-        2usize,
-        concat!("Alignment of ", stringify!(tagLC_ID))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).wLanguage) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(tagLC_ID),
-            "::",
-            stringify!(wLanguage)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).wCountry) as usize - ptr as usize },
-        2usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(tagLC_ID),
-            "::",
-            stringify!(wCountry)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).wCodePage) as usize - ptr as usize },
-        4usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(tagLC_ID),
-            "::",
-            stringify!(wCodePage)
-        )
-    );
-}
-pub type LC_ID = tagLC_ID;
-pub type LPLC_ID = *mut tagLC_ID;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct threadlocaleinfostruct {
-    pub refcount: ::std::os::raw::c_int,
-    pub lc_codepage: ::std::os::raw::c_uint,
-    pub lc_collate_cp: ::std::os::raw::c_uint,
-    pub lc_handle: [::std::os::raw::c_ulong; 6usize],
-    pub lc_id: [LC_ID; 6usize],
-    pub lc_category: [threadlocaleinfostruct__bindgen_ty_1; 6usize],
-    pub lc_clike: ::std::os::raw::c_int,
-    pub mb_cur_max: ::std::os::raw::c_int,
-    pub lconv_intl_refcount: *mut ::std::os::raw::c_int,
-    pub lconv_num_refcount: *mut ::std::os::raw::c_int,
-    pub lconv_mon_refcount: *mut ::std::os::raw::c_int,
-    pub lconv: *mut lconv,
-    pub ctype1_refcount: *mut ::std::os::raw::c_int,
-    pub ctype1: *mut ::std::os::raw::c_ushort,
-    pub pctype: *const ::std::os::raw::c_ushort,
-    pub pclmap: *const ::std::os::raw::c_uchar,
-    pub pcumap: *const ::std::os::raw::c_uchar,
-    pub lc_time_curr: *mut __lc_time_data,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct threadlocaleinfostruct__bindgen_ty_1 {
-    pub locale: *mut ::std::os::raw::c_char,
-    pub wlocale: *mut u16,
-    pub refcount: *mut ::std::os::raw::c_int,
-    pub wrefcount: *mut ::std::os::raw::c_int,
-}
-#[test]
-fn bindgen_test_layout_threadlocaleinfostruct__bindgen_ty_1() {
-    const UNINIT: ::std::mem::MaybeUninit<threadlocaleinfostruct__bindgen_ty_1> =
-        ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<threadlocaleinfostruct__bindgen_ty_1>(),
-        32usize,
-        concat!(
-            "Size of: ",
-            stringify!(threadlocaleinfostruct__bindgen_ty_1)
-        )
-    );
-    assert_eq!(
-        ::std::mem::align_of::<threadlocaleinfostruct__bindgen_ty_1>(),
-        8usize,
-        concat!(
-            "Alignment of ",
-            stringify!(threadlocaleinfostruct__bindgen_ty_1)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).locale) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct__bindgen_ty_1),
-            "::",
-            stringify!(locale)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).wlocale) as usize - ptr as usize },
-        8usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct__bindgen_ty_1),
-            "::",
-            stringify!(wlocale)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).refcount) as usize - ptr as usize },
-        16usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct__bindgen_ty_1),
-            "::",
-            stringify!(refcount)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).wrefcount) as usize - ptr as usize },
-        24usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct__bindgen_ty_1),
-            "::",
-            stringify!(wrefcount)
-        )
-    );
-}
-#[test]
-fn bindgen_test_layout_threadlocaleinfostruct() {
-    const UNINIT: ::std::mem::MaybeUninit<threadlocaleinfostruct> =
-        ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<threadlocaleinfostruct>(),
-        352usize,
-        concat!("Size of: ", stringify!(threadlocaleinfostruct))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<threadlocaleinfostruct>(),
-        8usize,
-        concat!("Alignment of ", stringify!(threadlocaleinfostruct))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).refcount) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(refcount)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).lc_codepage) as usize - ptr as usize },
-        4usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(lc_codepage)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).lc_collate_cp) as usize - ptr as usize },
-        8usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(lc_collate_cp)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).lc_handle) as usize - ptr as usize },
-        12usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(lc_handle)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).lc_id) as usize - ptr as usize },
-        36usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(lc_id)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).lc_category) as usize - ptr as usize },
-        72usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(lc_category)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).lc_clike) as usize - ptr as usize },
-        264usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(lc_clike)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).mb_cur_max) as usize - ptr as usize },
-        268usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(mb_cur_max)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).lconv_intl_refcount) as usize - ptr as usize },
-        272usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(lconv_intl_refcount)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).lconv_num_refcount) as usize - ptr as usize },
-        280usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(lconv_num_refcount)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).lconv_mon_refcount) as usize - ptr as usize },
-        288usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(lconv_mon_refcount)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).lconv) as usize - ptr as usize },
-        296usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(lconv)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).ctype1_refcount) as usize - ptr as usize },
-        304usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(ctype1_refcount)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).ctype1) as usize - ptr as usize },
-        312usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(ctype1)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).pctype) as usize - ptr as usize },
-        320usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(pctype)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).pclmap) as usize - ptr as usize },
-        328usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(pclmap)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).pcumap) as usize - ptr as usize },
-        336usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(pcumap)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).lc_time_curr) as usize - ptr as usize },
-        344usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(threadlocaleinfostruct),
-            "::",
-            stringify!(lc_time_curr)
-        )
-    );
-}
-pub type threadlocinfo = threadlocaleinfostruct;
-extern "C" {
-    pub fn _memccpy(
-        _Dst: *mut ::std::os::raw::c_void,
-        _Src: *const ::std::os::raw::c_void,
-        _Val: ::std::os::raw::c_int,
-        _MaxCount: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn memchr(
-        _Buf: *const ::std::os::raw::c_void,
-        _Val: ::std::os::raw::c_int,
-        _MaxCount: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn _memicmp(
-        _Buf1: *const ::std::os::raw::c_void,
-        _Buf2: *const ::std::os::raw::c_void,
-        _Size: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _memicmp_l(
-        _Buf1: *const ::std::os::raw::c_void,
-        _Buf2: *const ::std::os::raw::c_void,
-        _Size: usize,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn memcmp(
-        _Buf1: *const ::std::os::raw::c_void,
-        _Buf2: *const ::std::os::raw::c_void,
-        _Size: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn memcpy(
-        _Dst: *mut ::std::os::raw::c_void,
-        _Src: *const ::std::os::raw::c_void,
-        _Size: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn memcpy_s(
-        _dest: *mut ::std::os::raw::c_void,
-        _numberOfElements: usize,
-        _src: *const ::std::os::raw::c_void,
-        _count: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn mempcpy(
-        _Dst: *mut ::std::os::raw::c_void,
-        _Src: *const ::std::os::raw::c_void,
-        _Size: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn memset(
-        _Dst: *mut ::std::os::raw::c_void,
-        _Val: ::std::os::raw::c_int,
-        _Size: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn memccpy(
-        _Dst: *mut ::std::os::raw::c_void,
-        _Src: *const ::std::os::raw::c_void,
-        _Val: ::std::os::raw::c_int,
-        _Size: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn memicmp(
-        _Buf1: *const ::std::os::raw::c_void,
-        _Buf2: *const ::std::os::raw::c_void,
-        _Size: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _strset(
-        _Str: *mut ::std::os::raw::c_char,
-        _Val: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _strset_l(
-        _Str: *mut ::std::os::raw::c_char,
-        _Val: ::std::os::raw::c_int,
-        _Locale: _locale_t,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strcpy(
-        _Dest: *mut ::std::os::raw::c_char,
-        _Source: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strcat(
-        _Dest: *mut ::std::os::raw::c_char,
-        _Source: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strcmp(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn strlen(_Str: *const ::std::os::raw::c_char) -> usize;
-}
-extern "C" {
-    pub fn strnlen(_Str: *const ::std::os::raw::c_char, _MaxCount: usize) -> usize;
-}
-extern "C" {
-    pub fn memmove(
-        _Dst: *mut ::std::os::raw::c_void,
-        _Src: *const ::std::os::raw::c_void,
-        _Size: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn _strdup(_Src: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strchr(
-        _Str: *const ::std::os::raw::c_char,
-        _Val: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _stricmp(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _strcmpi(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _stricmp_l(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn strcoll(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _strcoll_l(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _stricoll(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _stricoll_l(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _strncoll(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _strncoll_l(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _strnicoll(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _strnicoll_l(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn strcspn(
-        _Str: *const ::std::os::raw::c_char,
-        _Control: *const ::std::os::raw::c_char,
-    ) -> usize;
-}
-extern "C" {
-    pub fn _strerror(_ErrMsg: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strerror(arg1: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _strlwr(_String: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strlwr_l(
-        _String: *mut ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strncat(
-        _Dest: *mut ::std::os::raw::c_char,
-        _Source: *const ::std::os::raw::c_char,
-        _Count: usize,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strncmp(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _strnicmp(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _strnicmp_l(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn strncpy(
-        _Dest: *mut ::std::os::raw::c_char,
-        _Source: *const ::std::os::raw::c_char,
-        _Count: usize,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _strnset(
-        _Str: *mut ::std::os::raw::c_char,
-        _Val: ::std::os::raw::c_int,
-        _MaxCount: usize,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _strnset_l(
-        str_: *mut ::std::os::raw::c_char,
-        c: ::std::os::raw::c_int,
-        count: usize,
-        _Locale: _locale_t,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strpbrk(
-        _Str: *const ::std::os::raw::c_char,
-        _Control: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strrchr(
-        _Str: *const ::std::os::raw::c_char,
-        _Ch: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _strrev(_Str: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strspn(
-        _Str: *const ::std::os::raw::c_char,
-        _Control: *const ::std::os::raw::c_char,
-    ) -> usize;
-}
-extern "C" {
-    pub fn strstr(
-        _Str: *const ::std::os::raw::c_char,
-        _SubStr: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strtok(
-        _Str: *mut ::std::os::raw::c_char,
-        _Delim: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strtok_r(
-        _Str: *mut ::std::os::raw::c_char,
-        _Delim: *const ::std::os::raw::c_char,
-        __last: *mut *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _strupr(_String: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _strupr_l(
-        _String: *mut ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strxfrm(
-        _Dst: *mut ::std::os::raw::c_char,
-        _Src: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-    ) -> usize;
-}
-extern "C" {
-    pub fn _strxfrm_l(
-        _Dst: *mut ::std::os::raw::c_char,
-        _Src: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> usize;
-}
-extern "C" {
-    pub fn strdup(_Src: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strcmpi(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn stricmp(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn strlwr(_Str: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strnicmp(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn strncasecmp(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *const ::std::os::raw::c_char,
-        arg3: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn strcasecmp(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn strnset(
-        _Str: *mut ::std::os::raw::c_char,
-        _Val: ::std::os::raw::c_int,
-        _MaxCount: usize,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strrev(_Str: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strset(
-        _Str: *mut ::std::os::raw::c_char,
-        _Val: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strupr(_Str: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _wcsdup(_Str: *const u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcscat(_Dest: *mut u16, _Source: *const u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcschr(_Str: *const u16, _Ch: u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcscmp(_Str1: *const u16, _Str2: *const u16) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn wcscpy(_Dest: *mut u16, _Source: *const u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcscspn(_Str: *const u16, _Control: *const u16) -> usize;
-}
-extern "C" {
-    pub fn wcslen(_Str: *const u16) -> usize;
-}
-extern "C" {
-    pub fn wcsnlen(_Src: *const u16, _MaxCount: usize) -> usize;
-}
-extern "C" {
-    pub fn wcsncat(_Dest: *mut u16, _Source: *const u16, _Count: usize) -> *mut u16;
-}
-extern "C" {
-    pub fn wcsncmp(_Str1: *const u16, _Str2: *const u16, _MaxCount: usize)
-        -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn wcsncpy(_Dest: *mut u16, _Source: *const u16, _Count: usize) -> *mut u16;
-}
-extern "C" {
-    pub fn _wcsncpy_l(
-        _Dest: *mut u16,
-        _Source: *const u16,
-        _Count: usize,
-        _Locale: _locale_t,
-    ) -> *mut u16;
-}
-extern "C" {
-    pub fn wcspbrk(_Str: *const u16, _Control: *const u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcsrchr(_Str: *const u16, _Ch: u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcsspn(_Str: *const u16, _Control: *const u16) -> usize;
-}
-extern "C" {
-    pub fn wcsstr(_Str: *const u16, _SubStr: *const u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcstok(_Str: *mut u16, _Delim: *const u16) -> *mut u16;
-}
-extern "C" {
-    pub fn _wcserror(_ErrNum: ::std::os::raw::c_int) -> *mut u16;
-}
-extern "C" {
-    pub fn __wcserror(_Str: *const u16) -> *mut u16;
-}
-extern "C" {
-    pub fn _wcsicmp(_Str1: *const u16, _Str2: *const u16) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wcsicmp_l(
-        _Str1: *const u16,
-        _Str2: *const u16,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wcsnicmp(
-        _Str1: *const u16,
-        _Str2: *const u16,
-        _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wcsnicmp_l(
-        _Str1: *const u16,
-        _Str2: *const u16,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wcsnset(_Str: *mut u16, _Val: u16, _MaxCount: usize) -> *mut u16;
-}
-extern "C" {
-    pub fn _wcsrev(_Str: *mut u16) -> *mut u16;
-}
-extern "C" {
-    pub fn _wcsset(_Str: *mut u16, _Val: u16) -> *mut u16;
-}
-extern "C" {
-    pub fn _wcslwr(_String: *mut u16) -> *mut u16;
-}
-extern "C" {
-    pub fn _wcslwr_l(_String: *mut u16, _Locale: _locale_t) -> *mut u16;
-}
-extern "C" {
-    pub fn _wcsupr(_String: *mut u16) -> *mut u16;
-}
-extern "C" {
-    pub fn _wcsupr_l(_String: *mut u16, _Locale: _locale_t) -> *mut u16;
-}
-extern "C" {
-    pub fn wcsxfrm(_Dst: *mut u16, _Src: *const u16, _MaxCount: usize) -> usize;
-}
-extern "C" {
-    pub fn _wcsxfrm_l(
-        _Dst: *mut u16,
-        _Src: *const u16,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> usize;
-}
-extern "C" {
-    pub fn wcscoll(_Str1: *const u16, _Str2: *const u16) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wcscoll_l(
-        _Str1: *const u16,
-        _Str2: *const u16,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wcsicoll(_Str1: *const u16, _Str2: *const u16) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wcsicoll_l(
-        _Str1: *const u16,
-        _Str2: *const u16,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wcsncoll(
-        _Str1: *const u16,
-        _Str2: *const u16,
-        _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wcsncoll_l(
-        _Str1: *const u16,
-        _Str2: *const u16,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wcsnicoll(
-        _Str1: *const u16,
-        _Str2: *const u16,
-        _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wcsnicoll_l(
-        _Str1: *const u16,
-        _Str2: *const u16,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn wcsdup(_Str: *const u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcsicmp(_Str1: *const u16, _Str2: *const u16) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn wcsnicmp(
-        _Str1: *const u16,
-        _Str2: *const u16,
-        _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn wcsnset(_Str: *mut u16, _Val: u16, _MaxCount: usize) -> *mut u16;
-}
-extern "C" {
-    pub fn wcsrev(_Str: *mut u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcsset(_Str: *mut u16, _Val: u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcslwr(_Str: *mut u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcsupr(_Str: *mut u16) -> *mut u16;
-}
-extern "C" {
-    pub fn wcsicoll(_Str1: *const u16, _Str2: *const u16) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _strset_s(
-        _Dst: *mut ::std::os::raw::c_char,
-        _DstSize: usize,
-        _Value: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _strerror_s(
-        _Buf: *mut ::std::os::raw::c_char,
-        _SizeInBytes: usize,
-        _ErrMsg: *const ::std::os::raw::c_char,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn strerror_s(
-        _Buf: *mut ::std::os::raw::c_char,
-        _SizeInBytes: usize,
-        _ErrNum: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _strlwr_s(_Str: *mut ::std::os::raw::c_char, _Size: usize) -> errno_t;
-}
-extern "C" {
-    pub fn _strlwr_s_l(
-        _Str: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Locale: _locale_t,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _strnset_s(
-        _Str: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Val: ::std::os::raw::c_int,
-        _MaxCount: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _strupr_s(_Str: *mut ::std::os::raw::c_char, _Size: usize) -> errno_t;
-}
-extern "C" {
-    pub fn _strupr_s_l(
-        _Str: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Locale: _locale_t,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn strncat_s(
-        _Dst: *mut ::std::os::raw::c_char,
-        _DstSizeInChars: usize,
-        _Src: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _strncat_s_l(
-        _Dst: *mut ::std::os::raw::c_char,
-        _DstSizeInChars: usize,
-        _Src: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn strcpy_s(
-        _Dst: *mut ::std::os::raw::c_char,
-        _SizeInBytes: rsize_t,
-        _Src: *const ::std::os::raw::c_char,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn strncpy_s(
-        _Dst: *mut ::std::os::raw::c_char,
-        _DstSizeInChars: usize,
-        _Src: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _strncpy_s_l(
-        _Dst: *mut ::std::os::raw::c_char,
-        _DstSizeInChars: usize,
-        _Src: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn strtok_s(
-        _Str: *mut ::std::os::raw::c_char,
-        _Delim: *const ::std::os::raw::c_char,
-        _Context: *mut *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _strtok_s_l(
-        _Str: *mut ::std::os::raw::c_char,
-        _Delim: *const ::std::os::raw::c_char,
-        _Context: *mut *mut ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn strcat_s(
-        _Dst: *mut ::std::os::raw::c_char,
-        _SizeInBytes: rsize_t,
-        _Src: *const ::std::os::raw::c_char,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn memmove_s(
-        _dest: *mut ::std::os::raw::c_void,
-        _numberOfElements: usize,
-        _src: *const ::std::os::raw::c_void,
-        _count: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn wcstok_s(_Str: *mut u16, _Delim: *const u16, _Context: *mut *mut u16) -> *mut u16;
-}
-extern "C" {
-    pub fn _wcserror_s(
-        _Buf: *mut u16,
-        _SizeInWords: usize,
-        _ErrNum: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn __wcserror_s(_Buffer: *mut u16, _SizeInWords: usize, _ErrMsg: *const u16) -> errno_t;
-}
-extern "C" {
-    pub fn _wcsnset_s(
-        _Dst: *mut u16,
-        _DstSizeInWords: usize,
-        _Val: u16,
-        _MaxCount: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wcsset_s(_Str: *mut u16, _SizeInWords: usize, _Val: u16) -> errno_t;
-}
-extern "C" {
-    pub fn _wcslwr_s(_Str: *mut u16, _SizeInWords: usize) -> errno_t;
-}
-extern "C" {
-    pub fn _wcslwr_s_l(_Str: *mut u16, _SizeInWords: usize, _Locale: _locale_t) -> errno_t;
-}
-extern "C" {
-    pub fn _wcsupr_s(_Str: *mut u16, _Size: usize) -> errno_t;
-}
-extern "C" {
-    pub fn _wcsupr_s_l(_Str: *mut u16, _Size: usize, _Locale: _locale_t) -> errno_t;
-}
-extern "C" {
-    pub fn wcscpy_s(_Dst: *mut u16, _SizeInWords: rsize_t, _Src: *const u16) -> errno_t;
-}
-extern "C" {
-    pub fn wcscat_s(_Dst: *mut u16, _SizeInWords: rsize_t, _Src: *const u16) -> errno_t;
-}
-extern "C" {
-    pub fn wcsncat_s(
-        _Dst: *mut u16,
-        _DstSizeInChars: usize,
-        _Src: *const u16,
-        _MaxCount: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wcsncat_s_l(
-        _Dst: *mut u16,
-        _DstSizeInChars: usize,
-        _Src: *const u16,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn wcsncpy_s(
-        _Dst: *mut u16,
-        _DstSizeInChars: usize,
-        _Src: *const u16,
-        _MaxCount: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wcsncpy_s_l(
-        _Dst: *mut u16,
-        _DstSizeInChars: usize,
-        _Src: *const u16,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wcstok_s_l(
-        _Str: *mut u16,
-        _Delim: *const u16,
-        _Context: *mut *mut u16,
-        _Locale: _locale_t,
-    ) -> *mut u16;
-}
-extern "C" {
-    pub fn _wcsset_s_l(
-        _Str: *mut u16,
-        _SizeInChars: usize,
-        _Val: u16,
-        _Locale: _locale_t,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wcsnset_s_l(
-        _Str: *mut u16,
-        _SizeInChars: usize,
-        _Val: u16,
-        _Count: usize,
-        _Locale: _locale_t,
-    ) -> errno_t;
-}
-pub type int_least8_t = ::std::os::raw::c_schar;
-pub type uint_least8_t = ::std::os::raw::c_uchar;
-pub type int_least16_t = ::std::os::raw::c_short;
-pub type uint_least16_t = ::std::os::raw::c_ushort;
-pub type int_least32_t = ::std::os::raw::c_int;
-pub type uint_least32_t = ::std::os::raw::c_uint;
-pub type int_least64_t = ::std::os::raw::c_longlong;
-pub type uint_least64_t = ::std::os::raw::c_ulonglong;
-pub type int_fast8_t = ::std::os::raw::c_schar;
-pub type uint_fast8_t = ::std::os::raw::c_uchar;
-pub type int_fast16_t = ::std::os::raw::c_short;
-pub type uint_fast16_t = ::std::os::raw::c_ushort;
-pub type int_fast32_t = ::std::os::raw::c_int;
-pub type uint_fast32_t = ::std::os::raw::c_uint;
-pub type int_fast64_t = ::std::os::raw::c_longlong;
-pub type uint_fast64_t = ::std::os::raw::c_ulonglong;
-pub type intmax_t = ::std::os::raw::c_longlong;
-pub type uintmax_t = ::std::os::raw::c_ulonglong;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct imaxdiv_t {
-    pub quot: intmax_t,
-    pub rem: intmax_t,
-}
-#[test]
-fn bindgen_test_layout_imaxdiv_t() {
-    const UNINIT: ::std::mem::MaybeUninit<imaxdiv_t> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<imaxdiv_t>(),
-        16usize,
-        concat!("Size of: ", stringify!(imaxdiv_t))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<imaxdiv_t>(),
-        8usize,
-        concat!("Alignment of ", stringify!(imaxdiv_t))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(imaxdiv_t),
-            "::",
-            stringify!(quot)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize },
-        8usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(imaxdiv_t),
-            "::",
-            stringify!(rem)
-        )
-    );
-}
-extern "C" {
-    pub fn imaxabs(j: intmax_t) -> intmax_t;
-}
-extern "C" {
-    pub fn imaxdiv(numer: intmax_t, denom: intmax_t) -> imaxdiv_t;
-}
-extern "C" {
-    pub fn strtoimax(
-        nptr: *const ::std::os::raw::c_char,
-        endptr: *mut *mut ::std::os::raw::c_char,
-        base: ::std::os::raw::c_int,
-    ) -> intmax_t;
-}
-extern "C" {
-    pub fn strtoumax(
-        nptr: *const ::std::os::raw::c_char,
-        endptr: *mut *mut ::std::os::raw::c_char,
-        base: ::std::os::raw::c_int,
-    ) -> uintmax_t;
-}
-extern "C" {
-    pub fn wcstoimax(
-        nptr: *const u16,
-        endptr: *mut *mut u16,
-        base: ::std::os::raw::c_int,
-    ) -> intmax_t;
-}
-extern "C" {
-    pub fn wcstoumax(
-        nptr: *const u16,
-        endptr: *mut *mut u16,
-        base: ::std::os::raw::c_int,
-    ) -> uintmax_t;
-}
-extern "C" {
-    pub fn _itow_s(
-        _Val: ::std::os::raw::c_int,
-        _DstBuf: *mut u16,
-        _SizeInWords: usize,
-        _Radix: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _ltow_s(
-        _Val: ::std::os::raw::c_long,
-        _DstBuf: *mut u16,
-        _SizeInWords: usize,
-        _Radix: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _ultow_s(
-        _Val: ::std::os::raw::c_ulong,
-        _DstBuf: *mut u16,
-        _SizeInWords: usize,
-        _Radix: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wgetenv_s(
-        _ReturnSize: *mut usize,
-        _DstBuf: *mut u16,
-        _DstSizeInWords: usize,
-        _VarName: *const u16,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wdupenv_s(
-        _Buffer: *mut *mut u16,
-        _BufferSizeInWords: *mut usize,
-        _VarName: *const u16,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _i64tow_s(
-        _Val: ::std::os::raw::c_longlong,
-        _DstBuf: *mut u16,
-        _SizeInWords: usize,
-        _Radix: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _ui64tow_s(
-        _Val: ::std::os::raw::c_ulonglong,
-        _DstBuf: *mut u16,
-        _SizeInWords: usize,
-        _Radix: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wmakepath_s(
-        _PathResult: *mut u16,
-        _SizeInWords: usize,
-        _Drive: *const u16,
-        _Dir: *const u16,
-        _Filename: *const u16,
-        _Ext: *const u16,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wputenv_s(_Name: *const u16, _Value: *const u16) -> errno_t;
-}
-extern "C" {
-    pub fn _wsearchenv_s(
-        _Filename: *const u16,
-        _EnvVar: *const u16,
-        _ResultPath: *mut u16,
-        _SizeInWords: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wsplitpath_s(
-        _FullPath: *const u16,
-        _Drive: *mut u16,
-        _DriveSizeInWords: usize,
-        _Dir: *mut u16,
-        _DirSizeInWords: usize,
-        _Filename: *mut u16,
-        _FilenameSizeInWords: usize,
-        _Ext: *mut u16,
-        _ExtSizeInWords: usize,
-    ) -> errno_t;
-}
-pub type _onexit_t = ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_int>;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _div_t {
-    pub quot: ::std::os::raw::c_int,
-    pub rem: ::std::os::raw::c_int,
-}
-#[test]
-fn bindgen_test_layout__div_t() {
-    const UNINIT: ::std::mem::MaybeUninit<_div_t> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<_div_t>(),
-        8usize,
-        concat!("Size of: ", stringify!(_div_t))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<_div_t>(),
-        4usize,
-        concat!("Alignment of ", stringify!(_div_t))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_div_t),
-            "::",
-            stringify!(quot)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize },
-        4usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_div_t),
-            "::",
-            stringify!(rem)
-        )
-    );
-}
-pub type div_t = _div_t;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _ldiv_t {
-    pub quot: ::std::os::raw::c_long,
-    pub rem: ::std::os::raw::c_long,
-}
-#[test]
-fn bindgen_test_layout__ldiv_t() {
-    const UNINIT: ::std::mem::MaybeUninit<_ldiv_t> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<_ldiv_t>(),
-        8usize,
-        concat!("Size of: ", stringify!(_ldiv_t))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<_ldiv_t>(),
-        4usize,
-        concat!("Alignment of ", stringify!(_ldiv_t))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_ldiv_t),
-            "::",
-            stringify!(quot)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize },
-        4usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_ldiv_t),
-            "::",
-            stringify!(rem)
-        )
-    );
-}
-pub type ldiv_t = _ldiv_t;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _LDOUBLE {
-    pub ld: [::std::os::raw::c_uchar; 10usize],
-}
-#[test]
-fn bindgen_test_layout__LDOUBLE() {
-    const UNINIT: ::std::mem::MaybeUninit<_LDOUBLE> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<_LDOUBLE>(),
-        10usize,
-        concat!("Size of: ", stringify!(_LDOUBLE))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<_LDOUBLE>(),
-        1usize,
-        concat!("Alignment of ", stringify!(_LDOUBLE))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).ld) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_LDOUBLE),
-            "::",
-            stringify!(ld)
-        )
-    );
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _CRT_DOUBLE {
-    pub x: f64,
-}
-#[test]
-fn bindgen_test_layout__CRT_DOUBLE() {
-    const UNINIT: ::std::mem::MaybeUninit<_CRT_DOUBLE> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<_CRT_DOUBLE>(),
-        8usize,
-        concat!("Size of: ", stringify!(_CRT_DOUBLE))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<_CRT_DOUBLE>(),
-        8usize,
-        concat!("Alignment of ", stringify!(_CRT_DOUBLE))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_CRT_DOUBLE),
-            "::",
-            stringify!(x)
-        )
-    );
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _CRT_FLOAT {
-    pub f: f32,
-}
-#[test]
-fn bindgen_test_layout__CRT_FLOAT() {
-    const UNINIT: ::std::mem::MaybeUninit<_CRT_FLOAT> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<_CRT_FLOAT>(),
-        4usize,
-        concat!("Size of: ", stringify!(_CRT_FLOAT))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<_CRT_FLOAT>(),
-        4usize,
-        concat!("Alignment of ", stringify!(_CRT_FLOAT))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).f) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_CRT_FLOAT),
-            "::",
-            stringify!(f)
-        )
-    );
-}
-#[repr(C)]
-#[repr(align(16))]
-#[derive(Debug, Copy, Clone)]
-pub struct _LONGDOUBLE {
-    pub x: u128,
-}
-#[test]
-fn bindgen_test_layout__LONGDOUBLE() {
-    const UNINIT: ::std::mem::MaybeUninit<_LONGDOUBLE> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<_LONGDOUBLE>(),
-        16usize,
-        concat!("Size of: ", stringify!(_LONGDOUBLE))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<_LONGDOUBLE>(),
-        16usize,
-        concat!("Alignment of ", stringify!(_LONGDOUBLE))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_LONGDOUBLE),
-            "::",
-            stringify!(x)
-        )
-    );
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _LDBL12 {
-    pub ld12: [::std::os::raw::c_uchar; 12usize],
-}
-#[test]
-fn bindgen_test_layout__LDBL12() {
-    const UNINIT: ::std::mem::MaybeUninit<_LDBL12> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<_LDBL12>(),
-        12usize,
-        concat!("Size of: ", stringify!(_LDBL12))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<_LDBL12>(),
-        1usize,
-        concat!("Alignment of ", stringify!(_LDBL12))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).ld12) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_LDBL12),
-            "::",
-            stringify!(ld12)
-        )
-    );
-}
-extern "C" {
-    pub static mut __imp___mb_cur_max: *mut ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn ___mb_cur_max_func() -> ::std::os::raw::c_int;
-}
-pub type _purecall_handler = ::std::option::Option<unsafe extern "C" fn()>;
-extern "C" {
-    pub fn _set_purecall_handler(_Handler: _purecall_handler) -> _purecall_handler;
-}
-extern "C" {
-    pub fn _get_purecall_handler() -> _purecall_handler;
-}
-pub type _invalid_parameter_handler = ::std::option::Option<
-    unsafe extern "C" fn(
-        arg1: *const u16,
-        arg2: *const u16,
-        arg3: *const u16,
-        arg4: ::std::os::raw::c_uint,
-        arg5: usize,
-    ),
->;
-extern "C" {
-    pub fn _set_invalid_parameter_handler(
-        _Handler: _invalid_parameter_handler,
-    ) -> _invalid_parameter_handler;
-}
-extern "C" {
-    pub fn _get_invalid_parameter_handler() -> _invalid_parameter_handler;
-}
-extern "C" {
-    pub fn _errno() -> *mut ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _set_errno(_Value: ::std::os::raw::c_int) -> errno_t;
-}
-extern "C" {
-    pub fn _get_errno(_Value: *mut ::std::os::raw::c_int) -> errno_t;
-}
-extern "C" {
-    pub fn __doserrno() -> *mut ::std::os::raw::c_ulong;
-}
-extern "C" {
-    pub fn _set_doserrno(_Value: ::std::os::raw::c_ulong) -> errno_t;
-}
-extern "C" {
-    pub fn _get_doserrno(_Value: *mut ::std::os::raw::c_ulong) -> errno_t;
-}
-extern "C" {
-    pub static mut _sys_errlist: [*mut ::std::os::raw::c_char; 1usize];
-}
-extern "C" {
-    pub static mut _sys_nerr: ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn __p___argv() -> *mut *mut *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn __p__fmode() -> *mut ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _get_pgmptr(_Value: *mut *mut ::std::os::raw::c_char) -> errno_t;
-}
-extern "C" {
-    pub fn _get_wpgmptr(_Value: *mut *mut u16) -> errno_t;
-}
-extern "C" {
-    pub fn _set_fmode(_Mode: ::std::os::raw::c_int) -> errno_t;
-}
-extern "C" {
-    pub fn _get_fmode(_PMode: *mut ::std::os::raw::c_int) -> errno_t;
-}
-extern "C" {
-    pub static mut __imp___argc: *mut ::std::os::raw::c_int;
-}
-extern "C" {
-    pub static mut __imp___argv: *mut *mut *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub static mut __imp___wargv: *mut *mut *mut u16;
-}
-extern "C" {
-    pub static mut __imp__environ: *mut *mut *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub static mut __imp__wenviron: *mut *mut *mut u16;
-}
-extern "C" {
-    pub static mut __imp__pgmptr: *mut *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub static mut __imp__wpgmptr: *mut *mut u16;
-}
-extern "C" {
-    pub static mut __imp__osplatform: *mut ::std::os::raw::c_uint;
-}
-extern "C" {
-    pub static mut __imp__osver: *mut ::std::os::raw::c_uint;
-}
-extern "C" {
-    pub static mut __imp__winver: *mut ::std::os::raw::c_uint;
-}
-extern "C" {
-    pub static mut __imp__winmajor: *mut ::std::os::raw::c_uint;
-}
-extern "C" {
-    pub static mut __imp__winminor: *mut ::std::os::raw::c_uint;
-}
-extern "C" {
-    pub fn _get_osplatform(_Value: *mut ::std::os::raw::c_uint) -> errno_t;
-}
-extern "C" {
-    pub fn _get_osver(_Value: *mut ::std::os::raw::c_uint) -> errno_t;
-}
-extern "C" {
-    pub fn _get_winver(_Value: *mut ::std::os::raw::c_uint) -> errno_t;
-}
-extern "C" {
-    pub fn _get_winmajor(_Value: *mut ::std::os::raw::c_uint) -> errno_t;
-}
-extern "C" {
-    pub fn _get_winminor(_Value: *mut ::std::os::raw::c_uint) -> errno_t;
-}
-extern "C" {
-    pub fn exit(_Code: ::std::os::raw::c_int) -> !;
-}
-extern "C" {
-    pub fn _exit(_Code: ::std::os::raw::c_int) -> !;
-}
-extern "C" {
-    pub fn _Exit(arg1: ::std::os::raw::c_int) -> !;
-}
-extern "C" {
-    pub fn abort() -> !;
-}
-extern "C" {
-    pub fn _set_abort_behavior(
-        _Flags: ::std::os::raw::c_uint,
-        _Mask: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_uint;
-}
-extern "C" {
-    pub fn abs(_X: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn labs(_X: ::std::os::raw::c_long) -> ::std::os::raw::c_long;
-}
-extern "C" {
-    pub fn atexit(arg1: ::std::option::Option<unsafe extern "C" fn()>) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn atof(_String: *const ::std::os::raw::c_char) -> f64;
-}
-extern "C" {
-    pub fn _atof_l(_String: *const ::std::os::raw::c_char, _Locale: _locale_t) -> f64;
-}
-extern "C" {
-    pub fn atoi(_Str: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _atoi_l(
-        _Str: *const ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn atol(_Str: *const ::std::os::raw::c_char) -> ::std::os::raw::c_long;
-}
-extern "C" {
-    pub fn _atol_l(
-        _Str: *const ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_long;
-}
-extern "C" {
-    pub fn bsearch(
-        _Key: *const ::std::os::raw::c_void,
-        _Base: *const ::std::os::raw::c_void,
-        _NumOfElements: usize,
-        _SizeOfElements: usize,
-        _PtFuncCompare: ::std::option::Option<
-            unsafe extern "C" fn(
-                arg1: *const ::std::os::raw::c_void,
-                arg2: *const ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
-        >,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn qsort(
-        _Base: *mut ::std::os::raw::c_void,
-        _NumOfElements: usize,
-        _SizeOfElements: usize,
-        _PtFuncCompare: ::std::option::Option<
-            unsafe extern "C" fn(
-                arg1: *const ::std::os::raw::c_void,
-                arg2: *const ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
-        >,
-    );
-}
-extern "C" {
-    pub fn _byteswap_ushort(_Short: ::std::os::raw::c_ushort) -> ::std::os::raw::c_ushort;
-}
-extern "C" {
-    pub fn _byteswap_ulong(_Long: ::std::os::raw::c_ulong) -> ::std::os::raw::c_ulong;
-}
-extern "C" {
-    pub fn _byteswap_uint64(_Int64: ::std::os::raw::c_ulonglong) -> ::std::os::raw::c_ulonglong;
-}
-extern "C" {
-    pub fn div(_Numerator: ::std::os::raw::c_int, _Denominator: ::std::os::raw::c_int) -> div_t;
-}
-extern "C" {
-    pub fn getenv(_VarName: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _itoa(
-        _Value: ::std::os::raw::c_int,
-        _Dest: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _i64toa(
-        _Val: ::std::os::raw::c_longlong,
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _ui64toa(
-        _Val: ::std::os::raw::c_ulonglong,
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _atoi64(_String: *const ::std::os::raw::c_char) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn _atoi64_l(
-        _String: *const ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn _strtoi64(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn _strtoi64_l(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn _strtoui64(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
-}
-extern "C" {
-    pub fn _strtoui64_l(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_ulonglong;
-}
-extern "C" {
-    pub fn ldiv(_Numerator: ::std::os::raw::c_long, _Denominator: ::std::os::raw::c_long)
-        -> ldiv_t;
-}
-extern "C" {
-    pub fn _ltoa(
-        _Value: ::std::os::raw::c_long,
-        _Dest: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn mblen(_Ch: *const ::std::os::raw::c_char, _MaxCount: usize) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _mblen_l(
-        _Ch: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _mbstrlen(_Str: *const ::std::os::raw::c_char) -> usize;
-}
-extern "C" {
-    pub fn _mbstrlen_l(_Str: *const ::std::os::raw::c_char, _Locale: _locale_t) -> usize;
-}
-extern "C" {
-    pub fn _mbstrnlen(_Str: *const ::std::os::raw::c_char, _MaxCount: usize) -> usize;
-}
-extern "C" {
-    pub fn _mbstrnlen_l(
-        _Str: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> usize;
-}
-extern "C" {
-    pub fn mbtowc(
-        _DstCh: *mut u16,
-        _SrcCh: *const ::std::os::raw::c_char,
-        _SrcSizeInBytes: usize,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _mbtowc_l(
-        _DstCh: *mut u16,
-        _SrcCh: *const ::std::os::raw::c_char,
-        _SrcSizeInBytes: usize,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn mbstowcs(
-        _Dest: *mut u16,
-        _Source: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-    ) -> usize;
-}
-extern "C" {
-    pub fn _mbstowcs_l(
-        _Dest: *mut u16,
-        _Source: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> usize;
-}
-extern "C" {
-    pub fn mkstemp(template_name: *mut ::std::os::raw::c_char) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn rand() -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _set_error_mode(_Mode: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn srand(_Seed: ::std::os::raw::c_uint);
-}
-extern "C" {
-    pub fn strtold(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
-    ) -> u128;
-}
-extern "C" {
-    pub fn __strtod(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
-    ) -> f64;
-}
-extern "C" {
-    pub fn __mingw_strtof(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
-    ) -> f32;
-}
-extern "C" {
-    pub fn __mingw_strtod(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
-    ) -> f64;
-}
-extern "C" {
-    pub fn __mingw_strtold(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
-    ) -> u128;
-}
-extern "C" {
-    pub fn _strtof_l(
-        _Str: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> f32;
-}
-extern "C" {
-    pub fn _strtod_l(
-        _Str: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> f64;
-}
-extern "C" {
-    pub fn strtol(
-        _Str: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_long;
-}
-extern "C" {
-    pub fn _strtol_l(
-        _Str: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_long;
-}
-extern "C" {
-    pub fn strtoul(
-        _Str: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulong;
-}
-extern "C" {
-    pub fn _strtoul_l(
-        _Str: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_ulong;
-}
-extern "C" {
-    pub fn system(_Command: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _ultoa(
-        _Value: ::std::os::raw::c_ulong,
-        _Dest: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn wctomb(_MbCh: *mut ::std::os::raw::c_char, _WCh: u16) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wctomb_l(
-        _MbCh: *mut ::std::os::raw::c_char,
-        _WCh: u16,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn wcstombs(
-        _Dest: *mut ::std::os::raw::c_char,
-        _Source: *const u16,
-        _MaxCount: usize,
-    ) -> usize;
-}
-extern "C" {
-    pub fn _wcstombs_l(
-        _Dest: *mut ::std::os::raw::c_char,
-        _Source: *const u16,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> usize;
-}
-extern "C" {
-    pub fn calloc(_NumOfElements: usize, _SizeOfElements: usize) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn free(_Memory: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn malloc(_Size: usize) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn realloc(
-        _Memory: *mut ::std::os::raw::c_void,
-        _NewSize: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn _aligned_free(_Memory: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn _aligned_malloc(_Size: usize, _Alignment: usize) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn _aligned_offset_malloc(
-        _Size: usize,
-        _Alignment: usize,
-        _Offset: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn _aligned_realloc(
-        _Memory: *mut ::std::os::raw::c_void,
-        _Size: usize,
-        _Alignment: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn _aligned_offset_realloc(
-        _Memory: *mut ::std::os::raw::c_void,
-        _Size: usize,
-        _Alignment: usize,
-        _Offset: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn _itow(
-        _Value: ::std::os::raw::c_int,
-        _Dest: *mut u16,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut u16;
-}
-extern "C" {
-    pub fn _ltow(
-        _Value: ::std::os::raw::c_long,
-        _Dest: *mut u16,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut u16;
-}
-extern "C" {
-    pub fn _ultow(
-        _Value: ::std::os::raw::c_ulong,
-        _Dest: *mut u16,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut u16;
-}
-extern "C" {
-    pub fn __mingw_wcstod(_Str: *const u16, _EndPtr: *mut *mut u16) -> f64;
-}
-extern "C" {
-    pub fn __mingw_wcstof(nptr: *const u16, endptr: *mut *mut u16) -> f32;
-}
-extern "C" {
-    pub fn __mingw_wcstold(arg1: *const u16, arg2: *mut *mut u16) -> u128;
-}
-extern "C" {
-    pub fn wcstold(arg1: *const u16, arg2: *mut *mut u16) -> u128;
-}
-extern "C" {
-    pub fn _wcstod_l(_Str: *const u16, _EndPtr: *mut *mut u16, _Locale: _locale_t) -> f64;
-}
-extern "C" {
-    pub fn _wcstof_l(_Str: *const u16, _EndPtr: *mut *mut u16, _Locale: _locale_t) -> f32;
-}
-extern "C" {
-    pub fn wcstol(
-        _Str: *const u16,
-        _EndPtr: *mut *mut u16,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_long;
-}
-extern "C" {
-    pub fn _wcstol_l(
-        _Str: *const u16,
-        _EndPtr: *mut *mut u16,
-        _Radix: ::std::os::raw::c_int,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_long;
-}
-extern "C" {
-    pub fn wcstoul(
-        _Str: *const u16,
-        _EndPtr: *mut *mut u16,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulong;
-}
-extern "C" {
-    pub fn _wcstoul_l(
-        _Str: *const u16,
-        _EndPtr: *mut *mut u16,
-        _Radix: ::std::os::raw::c_int,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_ulong;
-}
-extern "C" {
-    pub fn _wgetenv(_VarName: *const u16) -> *mut u16;
-}
-extern "C" {
-    pub fn _wsystem(_Command: *const u16) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wtof(_Str: *const u16) -> f64;
-}
-extern "C" {
-    pub fn _wtof_l(_Str: *const u16, _Locale: _locale_t) -> f64;
-}
-extern "C" {
-    pub fn _wtoi(_Str: *const u16) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wtoi_l(_Str: *const u16, _Locale: _locale_t) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wtol(_Str: *const u16) -> ::std::os::raw::c_long;
-}
-extern "C" {
-    pub fn _wtol_l(_Str: *const u16, _Locale: _locale_t) -> ::std::os::raw::c_long;
-}
-extern "C" {
-    pub fn _i64tow(
-        _Val: ::std::os::raw::c_longlong,
-        _DstBuf: *mut u16,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut u16;
-}
-extern "C" {
-    pub fn _ui64tow(
-        _Val: ::std::os::raw::c_ulonglong,
-        _DstBuf: *mut u16,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut u16;
-}
-extern "C" {
-    pub fn _wtoi64(_Str: *const u16) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn _wtoi64_l(_Str: *const u16, _Locale: _locale_t) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn _wcstoi64(
-        _Str: *const u16,
-        _EndPtr: *mut *mut u16,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn _wcstoi64_l(
-        _Str: *const u16,
-        _EndPtr: *mut *mut u16,
-        _Radix: ::std::os::raw::c_int,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn _wcstoui64(
-        _Str: *const u16,
-        _EndPtr: *mut *mut u16,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
-}
-extern "C" {
-    pub fn _wcstoui64_l(
-        _Str: *const u16,
-        _EndPtr: *mut *mut u16,
-        _Radix: ::std::os::raw::c_int,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_ulonglong;
-}
-extern "C" {
-    pub fn _putenv(_EnvString: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _wputenv(_EnvString: *const u16) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _fullpath(
-        _FullPath: *mut ::std::os::raw::c_char,
-        _Path: *const ::std::os::raw::c_char,
-        _SizeInBytes: usize,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _ecvt(
-        _Val: f64,
-        _NumOfDigits: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _fcvt(
-        _Val: f64,
-        _NumOfDec: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _gcvt(
-        _Val: f64,
-        _NumOfDigits: ::std::os::raw::c_int,
-        _DstBuf: *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn _atodbl(
-        _Result: *mut _CRT_DOUBLE,
-        _Str: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _atoldbl(
-        _Result: *mut _LDOUBLE,
-        _Str: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _atoflt(
-        _Result: *mut _CRT_FLOAT,
-        _Str: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _atodbl_l(
-        _Result: *mut _CRT_DOUBLE,
-        _Str: *mut ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _atoldbl_l(
-        _Result: *mut _LDOUBLE,
-        _Str: *mut ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _atoflt_l(
-        _Result: *mut _CRT_FLOAT,
-        _Str: *mut ::std::os::raw::c_char,
-        _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _lrotl(
-        arg1: ::std::os::raw::c_ulong,
-        arg2: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulong;
-}
-extern "C" {
-    pub fn _lrotr(
-        arg1: ::std::os::raw::c_ulong,
-        arg2: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulong;
-}
-extern "C" {
-    pub fn _makepath(
-        _Path: *mut ::std::os::raw::c_char,
-        _Drive: *const ::std::os::raw::c_char,
-        _Dir: *const ::std::os::raw::c_char,
-        _Filename: *const ::std::os::raw::c_char,
-        _Ext: *const ::std::os::raw::c_char,
-    );
-}
-extern "C" {
-    pub fn _onexit(_Func: _onexit_t) -> _onexit_t;
-}
-extern "C" {
-    pub fn perror(_ErrMsg: *const ::std::os::raw::c_char);
-}
-extern "C" {
-    pub fn _rotl64(
-        _Val: ::std::os::raw::c_ulonglong,
-        _Shift: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
-}
-extern "C" {
-    pub fn _rotr64(
-        Value: ::std::os::raw::c_ulonglong,
-        Shift: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
-}
-extern "C" {
-    pub fn _rotr(
-        _Val: ::std::os::raw::c_uint,
-        _Shift: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_uint;
-}
-extern "C" {
-    pub fn _rotl(
-        _Val: ::std::os::raw::c_uint,
-        _Shift: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_uint;
-}
-extern "C" {
-    pub fn _searchenv(
-        _Filename: *const ::std::os::raw::c_char,
-        _EnvVar: *const ::std::os::raw::c_char,
-        _ResultPath: *mut ::std::os::raw::c_char,
-    );
-}
-extern "C" {
-    pub fn _splitpath(
-        _FullPath: *const ::std::os::raw::c_char,
-        _Drive: *mut ::std::os::raw::c_char,
-        _Dir: *mut ::std::os::raw::c_char,
-        _Filename: *mut ::std::os::raw::c_char,
-        _Ext: *mut ::std::os::raw::c_char,
-    );
-}
-extern "C" {
-    pub fn _swab(
-        _Buf1: *mut ::std::os::raw::c_char,
-        _Buf2: *mut ::std::os::raw::c_char,
-        _SizeInBytes: ::std::os::raw::c_int,
-    );
-}
-extern "C" {
-    pub fn _wfullpath(_FullPath: *mut u16, _Path: *const u16, _SizeInWords: usize) -> *mut u16;
-}
-extern "C" {
-    pub fn _wmakepath(
-        _ResultPath: *mut u16,
-        _Drive: *const u16,
-        _Dir: *const u16,
-        _Filename: *const u16,
-        _Ext: *const u16,
-    );
-}
-extern "C" {
-    pub fn _wperror(_ErrMsg: *const u16);
-}
-extern "C" {
-    pub fn _wsearchenv(_Filename: *const u16, _EnvVar: *const u16, _ResultPath: *mut u16);
-}
-extern "C" {
-    pub fn _wsplitpath(
-        _FullPath: *const u16,
-        _Drive: *mut u16,
-        _Dir: *mut u16,
-        _Filename: *mut u16,
-        _Ext: *mut u16,
-    );
-}
-extern "C" {
-    pub fn _beep(_Frequency: ::std::os::raw::c_uint, _Duration: ::std::os::raw::c_uint);
-}
-extern "C" {
-    pub fn _seterrormode(_Mode: ::std::os::raw::c_int);
-}
-extern "C" {
-    pub fn _sleep(_Duration: ::std::os::raw::c_ulong);
-}
-extern "C" {
-    pub fn ecvt(
-        _Val: f64,
-        _NumOfDigits: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn fcvt(
-        _Val: f64,
-        _NumOfDec: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn gcvt(
-        _Val: f64,
-        _NumOfDigits: ::std::os::raw::c_int,
-        _DstBuf: *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn itoa(
-        _Val: ::std::os::raw::c_int,
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn ltoa(
-        _Val: ::std::os::raw::c_long,
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn putenv(_EnvString: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn swab(
-        _Buf1: *mut ::std::os::raw::c_char,
-        _Buf2: *mut ::std::os::raw::c_char,
-        _SizeInBytes: ::std::os::raw::c_int,
-    );
-}
-extern "C" {
-    pub fn ultoa(
-        _Val: ::std::os::raw::c_ulong,
-        _Dstbuf: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn onexit(_Func: _onexit_t) -> _onexit_t;
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct lldiv_t {
-    pub quot: ::std::os::raw::c_longlong,
-    pub rem: ::std::os::raw::c_longlong,
-}
-#[test]
-fn bindgen_test_layout_lldiv_t() {
-    const UNINIT: ::std::mem::MaybeUninit<lldiv_t> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<lldiv_t>(),
-        16usize,
-        concat!("Size of: ", stringify!(lldiv_t))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<lldiv_t>(),
-        8usize,
-        concat!("Alignment of ", stringify!(lldiv_t))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(lldiv_t),
-            "::",
-            stringify!(quot)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize },
-        8usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(lldiv_t),
-            "::",
-            stringify!(rem)
-        )
-    );
-}
-extern "C" {
-    pub fn lldiv(arg1: ::std::os::raw::c_longlong, arg2: ::std::os::raw::c_longlong) -> lldiv_t;
-}
-extern "C" {
-    pub fn llabs(arg1: ::std::os::raw::c_longlong) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn strtoll(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
-        arg3: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn strtoull(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
-        arg3: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
-}
-extern "C" {
-    pub fn atoll(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn wtoll(arg1: *const u16) -> ::std::os::raw::c_longlong;
-}
-extern "C" {
-    pub fn lltoa(
-        arg1: ::std::os::raw::c_longlong,
-        arg2: *mut ::std::os::raw::c_char,
-        arg3: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn ulltoa(
-        arg1: ::std::os::raw::c_ulonglong,
-        arg2: *mut ::std::os::raw::c_char,
-        arg3: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    pub fn lltow(
-        arg1: ::std::os::raw::c_longlong,
-        arg2: *mut u16,
-        arg3: ::std::os::raw::c_int,
-    ) -> *mut u16;
-}
-extern "C" {
-    pub fn ulltow(
-        arg1: ::std::os::raw::c_ulonglong,
-        arg2: *mut u16,
-        arg3: ::std::os::raw::c_int,
-    ) -> *mut u16;
-}
-extern "C" {
-    pub fn bsearch_s(
-        _Key: *const ::std::os::raw::c_void,
-        _Base: *const ::std::os::raw::c_void,
-        _NumOfElements: rsize_t,
-        _SizeOfElements: rsize_t,
-        _PtFuncCompare: ::std::option::Option<
-            unsafe extern "C" fn(
-                arg1: *mut ::std::os::raw::c_void,
-                arg2: *const ::std::os::raw::c_void,
-                arg3: *const ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
-        >,
-        _Context: *mut ::std::os::raw::c_void,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn _dupenv_s(
-        _PBuffer: *mut *mut ::std::os::raw::c_char,
-        _PBufferSizeInBytes: *mut usize,
-        _VarName: *const ::std::os::raw::c_char,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn getenv_s(
-        _ReturnSize: *mut usize,
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _DstSize: rsize_t,
-        _VarName: *const ::std::os::raw::c_char,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _itoa_s(
-        _Value: ::std::os::raw::c_int,
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Radix: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _i64toa_s(
-        _Val: ::std::os::raw::c_longlong,
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Radix: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _ui64toa_s(
-        _Val: ::std::os::raw::c_ulonglong,
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Radix: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _ltoa_s(
-        _Val: ::std::os::raw::c_long,
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Radix: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn mbstowcs_s(
-        _PtNumOfCharConverted: *mut usize,
-        _DstBuf: *mut u16,
-        _SizeInWords: usize,
-        _SrcBuf: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _mbstowcs_s_l(
-        _PtNumOfCharConverted: *mut usize,
-        _DstBuf: *mut u16,
-        _SizeInWords: usize,
-        _SrcBuf: *const ::std::os::raw::c_char,
-        _MaxCount: usize,
-        _Locale: _locale_t,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _ultoa_s(
-        _Val: ::std::os::raw::c_ulong,
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Radix: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn wctomb_s(
-        _SizeConverted: *mut ::std::os::raw::c_int,
-        _MbCh: *mut ::std::os::raw::c_char,
-        _SizeInBytes: rsize_t,
-        _WCh: u16,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wctomb_s_l(
-        _SizeConverted: *mut ::std::os::raw::c_int,
-        _MbCh: *mut ::std::os::raw::c_char,
-        _SizeInBytes: usize,
-        _WCh: u16,
-        _Locale: _locale_t,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn wcstombs_s(
-        _PtNumOfCharConverted: *mut usize,
-        _Dst: *mut ::std::os::raw::c_char,
-        _DstSizeInBytes: usize,
-        _Src: *const u16,
-        _MaxCountInBytes: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _wcstombs_s_l(
-        _PtNumOfCharConverted: *mut usize,
-        _Dst: *mut ::std::os::raw::c_char,
-        _DstSizeInBytes: usize,
-        _Src: *const u16,
-        _MaxCountInBytes: usize,
-        _Locale: _locale_t,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _ecvt_s(
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Val: f64,
-        _NumOfDights: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _fcvt_s(
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Val: f64,
-        _NumOfDec: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _gcvt_s(
-        _DstBuf: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Val: f64,
-        _NumOfDigits: ::std::os::raw::c_int,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _makepath_s(
-        _PathResult: *mut ::std::os::raw::c_char,
-        _Size: usize,
-        _Drive: *const ::std::os::raw::c_char,
-        _Dir: *const ::std::os::raw::c_char,
-        _Filename: *const ::std::os::raw::c_char,
-        _Ext: *const ::std::os::raw::c_char,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _putenv_s(
-        _Name: *const ::std::os::raw::c_char,
-        _Value: *const ::std::os::raw::c_char,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _searchenv_s(
-        _Filename: *const ::std::os::raw::c_char,
-        _EnvVar: *const ::std::os::raw::c_char,
-        _ResultPath: *mut ::std::os::raw::c_char,
-        _SizeInBytes: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn _splitpath_s(
-        _FullPath: *const ::std::os::raw::c_char,
-        _Drive: *mut ::std::os::raw::c_char,
-        _DriveSize: usize,
-        _Dir: *mut ::std::os::raw::c_char,
-        _DirSize: usize,
-        _Filename: *mut ::std::os::raw::c_char,
-        _FilenameSize: usize,
-        _Ext: *mut ::std::os::raw::c_char,
-        _ExtSize: usize,
-    ) -> errno_t;
-}
-extern "C" {
-    pub fn qsort_s(
-        _Base: *mut ::std::os::raw::c_void,
-        _NumOfElements: usize,
-        _SizeOfElements: usize,
-        _PtFuncCompare: ::std::option::Option<
-            unsafe extern "C" fn(
-                arg1: *mut ::std::os::raw::c_void,
-                arg2: *const ::std::os::raw::c_void,
-                arg3: *const ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
-        >,
-        _Context: *mut ::std::os::raw::c_void,
-    );
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _heapinfo {
-    pub _pentry: *mut ::std::os::raw::c_int,
-    pub _size: usize,
-    pub _useflag: ::std::os::raw::c_int,
-}
-#[test]
-fn bindgen_test_layout__heapinfo() {
-    const UNINIT: ::std::mem::MaybeUninit<_heapinfo> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<_heapinfo>(),
-        24usize,
-        concat!("Size of: ", stringify!(_heapinfo))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<_heapinfo>(),
-        8usize,
-        concat!("Alignment of ", stringify!(_heapinfo))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr)._pentry) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_heapinfo),
-            "::",
-            stringify!(_pentry)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr)._size) as usize - ptr as usize },
-        8usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_heapinfo),
-            "::",
-            stringify!(_size)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr)._useflag) as usize - ptr as usize },
-        16usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(_heapinfo),
-            "::",
-            stringify!(_useflag)
-        )
-    );
-}
-pub type _HEAPINFO = _heapinfo;
-extern "C" {
-    pub static mut _amblksiz: ::std::os::raw::c_uint;
-}
-extern "C" {
-    pub fn __mingw_aligned_malloc(_Size: usize, _Alignment: usize) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn __mingw_aligned_free(_Memory: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn __mingw_aligned_offset_realloc(
-        _Memory: *mut ::std::os::raw::c_void,
-        _Size: usize,
-        _Alignment: usize,
-        _Offset: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn __mingw_aligned_realloc(
-        _Memory: *mut ::std::os::raw::c_void,
-        _Size: usize,
-        _Offset: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn _resetstkoflw() -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _set_malloc_crt_max_wait(_NewValue: ::std::os::raw::c_ulong) -> ::std::os::raw::c_ulong;
-}
-extern "C" {
-    pub fn _expand(
-        _Memory: *mut ::std::os::raw::c_void,
-        _NewSize: usize,
-    ) -> *mut ::std::os::raw::c_void;
-}
-extern "C" {
-    pub fn _msize(_Memory: *mut ::std::os::raw::c_void) -> usize;
-}
-extern "C" {
-    pub fn _get_sbh_threshold() -> usize;
-}
-extern "C" {
-    pub fn _set_sbh_threshold(_NewValue: usize) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _set_amblksiz(_Value: usize) -> errno_t;
-}
-extern "C" {
-    pub fn _get_amblksiz(_Value: *mut usize) -> errno_t;
-}
-extern "C" {
-    pub fn _heapadd(_Memory: *mut ::std::os::raw::c_void, _Size: usize) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _heapchk() -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _heapmin() -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _heapset(_Fill: ::std::os::raw::c_uint) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _heapwalk(_EntryInfo: *mut _HEAPINFO) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    pub fn _heapused(_Used: *mut usize, _Commit: *mut usize) -> usize;
-}
-extern "C" {
-    pub fn _get_heap_handle() -> isize;
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct ogt_mesh_vec3 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-}
-#[test]
-fn bindgen_test_layout_ogt_mesh_vec3() {
-    const UNINIT: ::std::mem::MaybeUninit<ogt_mesh_vec3> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<ogt_mesh_vec3>(),
-        12usize,
-        concat!("Size of: ", stringify!(ogt_mesh_vec3))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<ogt_mesh_vec3>(),
-        4usize,
-        concat!("Alignment of ", stringify!(ogt_mesh_vec3))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh_vec3),
-            "::",
-            stringify!(x)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
-        4usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh_vec3),
-            "::",
-            stringify!(y)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).z) as usize - ptr as usize },
-        8usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh_vec3),
-            "::",
-            stringify!(z)
-        )
-    );
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct ogt_mesh_rgba {
-    pub r: u8,
-    pub g: u8,
-    pub b: u8,
-    pub a: u8,
-}
-#[test]
-fn bindgen_test_layout_ogt_mesh_rgba() {
-    const UNINIT: ::std::mem::MaybeUninit<ogt_mesh_rgba> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<ogt_mesh_rgba>(),
-        4usize,
-        concat!("Size of: ", stringify!(ogt_mesh_rgba))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<ogt_mesh_rgba>(),
-        1usize,
-        concat!("Alignment of ", stringify!(ogt_mesh_rgba))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).r) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh_rgba),
-            "::",
-            stringify!(r)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).g) as usize - ptr as usize },
-        1usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh_rgba),
-            "::",
-            stringify!(g)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).b) as usize - ptr as usize },
-        2usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh_rgba),
-            "::",
-            stringify!(b)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).a) as usize - ptr as usize },
-        3usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh_rgba),
-            "::",
-            stringify!(a)
-        )
-    );
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct ogt_mesh_vertex {
-    pub pos: ogt_mesh_vec3,
-    pub normal: ogt_mesh_vec3,
-    pub color: ogt_mesh_rgba,
-    pub palette_index: u32,
-}
-#[test]
-fn bindgen_test_layout_ogt_mesh_vertex() {
-    const UNINIT: ::std::mem::MaybeUninit<ogt_mesh_vertex> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<ogt_mesh_vertex>(),
-        32usize,
-        concat!("Size of: ", stringify!(ogt_mesh_vertex))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<ogt_mesh_vertex>(),
-        4usize,
-        concat!("Alignment of ", stringify!(ogt_mesh_vertex))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).pos) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh_vertex),
-            "::",
-            stringify!(pos)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).normal) as usize - ptr as usize },
-        12usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh_vertex),
-            "::",
-            stringify!(normal)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).color) as usize - ptr as usize },
-        24usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh_vertex),
-            "::",
-            stringify!(color)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).palette_index) as usize - ptr as usize },
-        28usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh_vertex),
-            "::",
-            stringify!(palette_index)
-        )
-    );
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct ogt_mesh {
-    pub vertex_count: u32,
-    pub index_count: u32,
-    pub vertices: *mut ogt_mesh_vertex,
-    pub indices: *mut u32,
-}
-#[test]
-fn bindgen_test_layout_ogt_mesh() {
-    const UNINIT: ::std::mem::MaybeUninit<ogt_mesh> = ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<ogt_mesh>(),
-        24usize,
-        concat!("Size of: ", stringify!(ogt_mesh))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<ogt_mesh>(),
-        8usize,
-        concat!("Alignment of ", stringify!(ogt_mesh))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).vertex_count) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh),
-            "::",
-            stringify!(vertex_count)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).index_count) as usize - ptr as usize },
-        4usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh),
-            "::",
-            stringify!(index_count)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).vertices) as usize - ptr as usize },
-        8usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh),
-            "::",
-            stringify!(vertices)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).indices) as usize - ptr as usize },
-        16usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_mesh),
-            "::",
-            stringify!(indices)
-        )
-    );
-}
-pub type ogt_voxel_meshify_alloc_func = ::std::option::Option<
-    unsafe extern "C" fn(
-        size: usize,
-        user_data: *mut ::std::os::raw::c_void,
-    ) -> *mut ::std::os::raw::c_void,
->;
-pub type ogt_voxel_meshify_free_func = ::std::option::Option<
-    unsafe extern "C" fn(ptr: *mut ::std::os::raw::c_void, user_data: *mut ::std::os::raw::c_void),
->;
-pub type ogt_voxel_simple_stream_func = ::std::option::Option<
-    unsafe extern "C" fn(
-        x: u32,
-        y: u32,
-        z: u32,
-        vertices: *const ogt_mesh_vertex,
-        vertex_count: u32,
-        indices: *const u32,
-        index_count: u32,
-        user_data: *mut ::std::os::raw::c_void,
-    ),
->;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct ogt_voxel_meshify_context {
-    pub alloc_func: ogt_voxel_meshify_alloc_func,
-    pub free_func: ogt_voxel_meshify_free_func,
-    pub alloc_free_user_data: *mut ::std::os::raw::c_void,
-}
-#[test]
-fn bindgen_test_layout_ogt_voxel_meshify_context() {
-    const UNINIT: ::std::mem::MaybeUninit<ogt_voxel_meshify_context> =
-        ::std::mem::MaybeUninit::uninit();
-    let ptr = UNINIT.as_ptr();
-    assert_eq!(
-        ::std::mem::size_of::<ogt_voxel_meshify_context>(),
-        24usize,
-        concat!("Size of: ", stringify!(ogt_voxel_meshify_context))
-    );
-    assert_eq!(
-        ::std::mem::align_of::<ogt_voxel_meshify_context>(),
-        8usize,
-        concat!("Alignment of ", stringify!(ogt_voxel_meshify_context))
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).alloc_func) as usize - ptr as usize },
-        0usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_voxel_meshify_context),
-            "::",
-            stringify!(alloc_func)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).free_func) as usize - ptr as usize },
-        8usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_voxel_meshify_context),
-            "::",
-            stringify!(free_func)
-        )
-    );
-    assert_eq!(
-        unsafe { ::std::ptr::addr_of!((*ptr).alloc_free_user_data) as usize - ptr as usize },
-        16usize,
-        concat!(
-            "Offset of field: ",
-            stringify!(ogt_voxel_meshify_context),
-            "::",
-            stringify!(alloc_free_user_data)
-        )
-    );
-}
-extern "C" {
-    #[link_name = "\u{1}_Z42ogt_face_count_from_paletted_voxels_simplePKhjjj"]
-    pub fn ogt_face_count_from_paletted_voxels_simple(
-        voxels: *const u8,
-        size_x: u32,
-        size_y: u32,
-        size_z: u32,
-    ) -> u32;
-}
-extern "C" {
-    #[link_name = "\u{1}_Z36ogt_mesh_from_paletted_voxels_simplePK25ogt_voxel_meshify_contextPKhjjjPK13ogt_mesh_rgba"]
-    pub fn ogt_mesh_from_paletted_voxels_simple(
-        ctx: *const ogt_voxel_meshify_context,
-        voxels: *const u8,
-        size_x: u32,
-        size_y: u32,
-        size_z: u32,
-        palette: *const ogt_mesh_rgba,
-    ) -> *mut ogt_mesh;
-}
-extern "C" {
-    #[link_name = "\u{1}_Z36ogt_mesh_from_paletted_voxels_greedyPK25ogt_voxel_meshify_contextPKhjjjPK13ogt_mesh_rgba"]
-    pub fn ogt_mesh_from_paletted_voxels_greedy(
-        ctx: *const ogt_voxel_meshify_context,
-        voxels: *const u8,
-        size_x: u32,
-        size_y: u32,
-        size_z: u32,
-        palette: *const ogt_mesh_rgba,
-    ) -> *mut ogt_mesh;
-}
-extern "C" {
-    #[link_name = "\u{1}_Z37ogt_mesh_from_paletted_voxels_polygonPK25ogt_voxel_meshify_contextPKhjjjPK13ogt_mesh_rgba"]
-    pub fn ogt_mesh_from_paletted_voxels_polygon(
-        ctx: *const ogt_voxel_meshify_context,
-        voxels: *const u8,
-        size_x: u32,
-        size_y: u32,
-        size_z: u32,
-        palette: *const ogt_mesh_rgba,
-    ) -> *mut ogt_mesh;
-}
-extern "C" {
-    #[link_name = "\u{1}_Z34ogt_mesh_remove_duplicate_verticesPK25ogt_voxel_meshify_contextP8ogt_mesh"]
-    pub fn ogt_mesh_remove_duplicate_vertices(
-        ctx: *const ogt_voxel_meshify_context,
-        mesh: *mut ogt_mesh,
-    );
-}
-extern "C" {
-    #[link_name = "\u{1}_Z23ogt_mesh_smooth_normalsPK25ogt_voxel_meshify_contextP8ogt_mesh"]
-    pub fn ogt_mesh_smooth_normals(ctx: *const ogt_voxel_meshify_context, mesh: *mut ogt_mesh);
-}
-extern "C" {
-    #[link_name = "\u{1}_Z16ogt_mesh_destroyPK25ogt_voxel_meshify_contextP8ogt_mesh"]
-    pub fn ogt_mesh_destroy(ctx: *const ogt_voxel_meshify_context, mesh: *mut ogt_mesh);
-}
-extern "C" {
-    #[link_name = "\u{1}_Z38ogt_stream_from_paletted_voxels_simplePKhjjjPK13ogt_mesh_rgbaPFvjjjPK15ogt_mesh_vertexjPKjjPvES9_"]
-    pub fn ogt_stream_from_paletted_voxels_simple(
-        voxels: *const u8,
-        size_x: u32,
-        size_y: u32,
-        size_z: u32,
-        palette: *const ogt_mesh_rgba,
-        stream_func: ogt_voxel_simple_stream_func,
-        stream_func_data: *mut ::std::os::raw::c_void,
-    );
-}
-pub type __builtin_va_list = *mut ::std::os::raw::c_char;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct lconv {
-    pub _address: u8,
-}
@@ -0,0 +1,629 @@
+// voxel ray tracing: two level DDA (blocks, then voxels inside non-empty block)
+// trace() is CPU reference of what shaders/voxel.glsl does on GPU, keep them in sync
+
+use std::fmt;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use glam::{IVec3, Vec3};
+use vulkano::buffer::{Buffer, BufferContents, BufferCreateInfo, BufferUsage, Subbuffer};
+use vulkano::command_buffer::allocator::StandardCommandBufferAllocator;
+use vulkano::command_buffer::{AutoCommandBufferBuilder, BufferImageCopy, CommandBufferUsage, CopyBufferToImageInfo, PrimaryAutoCommandBuffer, PrimaryCommandBufferAbstract};
+use vulkano::descriptor_set::allocator::StandardDescriptorSetAllocator;
+use vulkano::descriptor_set::{PersistentDescriptorSet, WriteDescriptorSet};
+use vulkano::device::{Device, Queue};
+use vulkano::format::Format;
+use vulkano::image::sampler::{Sampler, SamplerCreateInfo};
+use vulkano::image::view::ImageView;
+use vulkano::image::{Image, ImageCreateInfo, ImageType, ImageUsage};
+use vulkano::memory::allocator::{AllocationCreateInfo, MemoryAllocator, MemoryTypeFilter};
+use vulkano::pipeline::compute::ComputePipelineCreateInfo;
+use vulkano::pipeline::layout::PipelineDescriptorSetLayoutCreateInfo;
+use vulkano::pipeline::{ComputePipeline, Pipeline, PipelineBindPoint, PipelineLayout, PipelineShaderStageCreateInfo};
+use vulkano::shader::ShaderModule;
+use vulkano::sync::GpuFuture;
+use vulkano::DeviceSize;
+
+use crate::camera::Camera;
+use crate::world::{BlockID, BlockIndex, VoxelID, World, BLOCK_SIZE, MAX_BLOCKS};
+use crate::{upload_palette, MaterialGPU};
+
+///blocks per row (and rows per layer) of block_palette_image atlas, see atlas_offset
+/// ATLAS_ROW * BLOCK_SIZE stays within 256, smallest maxImageDimension3D Vulkan allows
+pub const ATLAS_ROW: usize = 16;
+///blocks in one BLOCK_SIZE deep layer of atlas
+const ATLAS_LAYER: usize = ATLAS_ROW*ATLAS_ROW;
+///format of united_blocks_image, one texel is one BlockIndex
+const BLOCK_ID_FORMAT: Format = match size_of::<BlockIndex>() {
+    1 => Format::R8_UINT,
+    2 => Format::R16_UINT,
+    _ => Format::R32_UINT,
+};
+///workgroup size of trace.comp in x and y
+const TRACE_GROUP: u32 = 8;
+
+///anything trace() can walk through
+pub trait Volume {
+    ///min (inclusive) and max (exclusive) block position, rays are clipped to it
+    fn block_bounds(&self) -> (IVec3, IVec3);
+    ///pos is in blocks and within block_bounds
+    fn block(&self, pos: IVec3) -> BlockID;
+    ///local is in 0..BLOCK_SIZE
+    fn voxel(&self, block: BlockID, local: IVec3) -> VoxelID;
+}
+
+///loaded chunks of world as one dense grid of block ids. Same layout as united_blocks_image
+pub struct VoxelVolume<'w> {
+    ///position of first block, in blocks
+    pub origin: IVec3,
+    ///in blocks
+    pub size: IVec3,
+    ///order is X -> Y -> Z
+    pub blocks: Vec<BlockID>,
+    world: &'w World,
+}
+
+impl<'w> VoxelVolume<'w> {
+    pub fn new(world: &'w World) -> Self {
+        let (origin, end) = world.loaded_bounds().unwrap_or((IVec3::ZERO, IVec3::ONE));
+        let size = end - origin;
+        let mut blocks = Vec::with_capacity((size.x * size.y * size.z) as usize);
+        for z in 0..size.z {
+        for y in 0..size.y {
+        for x in 0..size.x {
+            blocks.push(world.loaded_block(origin + IVec3::new(x, y, z)));
+        }
+        }
+        }
+        VoxelVolume {origin, size, blocks, world}
+    }
+}
+
+impl<'w> Volume for VoxelVolume<'w> {
+    fn block_bounds(&self) -> (IVec3, IVec3) {
+        (self.origin, self.origin + self.size)
+    }
+
+    fn block(&self, pos: IVec3) -> BlockID {
+        let local = pos - self.origin;
+        self.blocks[(local.x + local.y*self.size.x + local.z*self.size.x*self.size.y) as usize]
+    }
+
+    fn voxel(&self, block: BlockID, local: IVec3) -> VoxelID {
+        let voxels = self.world.get_block_from_palette(block);
+        voxels[local.x as usize + local.y as usize*BLOCK_SIZE + local.z as usize*BLOCK_SIZE*BLOCK_SIZE]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Ray {
+    pub origin: Vec3,
+    ///normalized, no zero components
+    pub direction: Vec3,
+}
+
+impl Ray {
+    pub fn new(origin: Vec3, direction: Vec3) -> Ray {
+        //zero components would make slab test and DDA divide 0 by 0
+        let direction = direction.normalize();
+        let direction = Vec3::select(direction.abs().cmplt(Vec3::splat(1e-6)), Vec3::splat(1e-6), direction);
+        Ray {origin, direction}
+    }
+
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.origin + self.direction * t
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hit {
+    ///distance along ray to where it enters voxel
+    pub t: f32,
+    ///in voxels
+    pub voxel: IVec3,
+    ///face ray entered through, zero if ray started inside of voxel
+    pub normal: IVec3,
+    pub id: VoxelID,
+}
+
+///Amanatides & Woo grid walk over cells of cell_size
+struct Dda {
+    cell: IVec3,
+    step: IVec3,
+    ///t of next boundary on each axis
+    t_max: Vec3,
+    t_delta: Vec3,
+    ///t where ray entered current cell
+    t: f32,
+    normal: IVec3,
+}
+
+impl Dda {
+    ///starts in cell that contains ray.at(t), clamped to lo..=hi (for when t is exactly on boundary)
+    fn new(ray: &Ray, cell_size: f32, t: f32, lo: IVec3, hi: IVec3, normal: IVec3) -> Dda {
+        let cell = (ray.at(t) / cell_size).floor().as_ivec3().clamp(lo, hi);
+        let step = ray.direction.signum().as_ivec3();
+        let next_boundary = (cell + step.max(IVec3::ZERO)).as_vec3() * cell_size;
+        Dda {
+            cell,
+            step,
+            t_max: (next_boundary - ray.origin) / ray.direction,
+            t_delta: (cell_size / ray.direction).abs(),
+            t,
+            normal,
+        }
+    }
+
+    fn next(&mut self) {
+        let axis = if self.t_max.x < self.t_max.y && self.t_max.x < self.t_max.z {0}
+            else if self.t_max.y < self.t_max.z {1}
+            else {2};
+        self.t = self.t_max[axis];
+        self.t_max[axis] += self.t_delta[axis];
+        self.cell[axis] += self.step[axis];
+        self.normal = IVec3::ZERO;
+        self.normal[axis] = -self.step[axis];
+    }
+}
+
+///first non-empty voxel along ray closer than max_t
+pub fn trace(volume: &impl Volume, ray: &Ray, max_t: f32) -> Option<Hit> {
+    let (lo, hi) = volume.block_bounds();
+    let block_size = BLOCK_SIZE as i32;
+
+    //clip ray to volume
+    let t0 = ((lo * block_size).as_vec3() - ray.origin) / ray.direction;
+    let t1 = ((hi * block_size).as_vec3() - ray.origin) / ray.direction;
+    let t_near = t0.min(t1);
+    let t_far = t0.max(t1);
+    let t_enter = t_near.max_element().max(0.0);
+    let t_exit = t_far.min_element().min(max_t);
+    if t_enter >= t_exit {
+        return None;
+    }
+    let mut normal = IVec3::ZERO;
+    if t_near.max_element() > 0.0 {
+        let axis = if t_near.x > t_near.y && t_near.x > t_near.z {0} else if t_near.y > t_near.z {1} else {2};
+        normal[axis] = -ray.direction.signum().as_ivec3()[axis];
+    }
+
+    let mut blocks = Dda::new(ray, BLOCK_SIZE as f32, t_enter, lo, hi - 1, normal);
+    loop {
+        let id = volume.block(blocks.cell);
+        if !id.is_empty() {
+            let base = blocks.cell * block_size;
+            let mut voxels = Dda::new(ray, 1.0, blocks.t, base, base + block_size - 1, blocks.normal);
+            loop {
+                if voxels.t >= t_exit {
+                    return None;
+                }
+                let voxel = volume.voxel(id, voxels.cell - base);
+                if !voxel.is_empty() {
+                    return Some(Hit {t: voxels.t, voxel: voxels.cell, normal: voxels.normal, id: voxel});
+                }
+                voxels.next();
+                let local = voxels.cell - base;
+                if local.cmplt(IVec3::ZERO).any() || local.cmpge(IVec3::splat(block_size)).any() {
+                    break;
+                }
+            }
+        }
+        blocks.next();
+        if blocks.t >= t_exit || blocks.cell.cmplt(lo).any() || blocks.cell.cmpge(hi).any() {
+            return None;
+        }
+    }
+}
+
+///GPU copy of VoxelVolume and palettes, everything voxel.glsl reads
+pub struct VolumeGPU {
+    ///same as VoxelVolume::origin when uploaded. Streaming keeps it while loaded chunks fit, unloaded ones read as empty
+    pub origin: IVec3,
+    pub size: IVec3,
+    ///3d image of block ids (BLOCK_ID_FORMAT), size of VoxelVolume
+    pub united_blocks_image: Arc<ImageView>,
+    ///R8_UINT 3d atlas of every block in block_palette, see atlas_offset
+    pub block_palette_image: Arc<ImageView>,
+    ///materials do not fit into one texel, so it is storage buffer (same as raster pass uses)
+    pub voxel_palette: Subbuffer<[MaterialGPU]>,
+}
+
+///first texel of block in block_palette_image: (b % ATLAS_ROW, b / ATLAS_ROW % ATLAS_ROW, b / ATLAS_LAYER) * BLOCK_SIZE
+/// same as get_voxel in voxel.glsl
+pub fn atlas_offset(id: BlockID) -> [u32; 3] {
+    let b = id.index();
+    [b % ATLAS_ROW, b / ATLAS_ROW % ATLAS_ROW, b / ATLAS_LAYER].map(|v| (v * BLOCK_SIZE) as u32)
+}
+
+///how many blocks block_palette_image can hold on this device (limited by maxImageDimension3D), at most MAX_BLOCKS
+/// World::max_blocks should not be above it
+pub fn atlas_capacity(device: &Device) -> usize {
+    let layers = device.physical_device().properties().max_image_dimension3_d as usize / BLOCK_SIZE;
+    (layers * ATLAS_LAYER).min(MAX_BLOCKS)
+}
+
+#[derive(Debug)]
+pub enum VolumeError {
+    ///world has more blocks than block_palette_image of this device holds, see atlas_capacity
+    TooManyBlocks { blocks: usize, capacity: usize },
+}
+
+impl fmt::Display for VolumeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VolumeError::TooManyBlocks { blocks, capacity } => write!(f, "world has {} blocks, but this GPU fits only {} into block atlas, lower --max-blocks", blocks, capacity),
+        }
+    }
+}
+
+impl std::error::Error for VolumeError {}
+
+///blocks that fit into already uploaded atlas
+fn uploaded_capacity(volume: &VolumeGPU) -> usize {
+    volume.block_palette_image.image().extent()[2] as usize / BLOCK_SIZE * ATLAS_LAYER
+}
+
+fn block_id_bytes(ids: impl Iterator<Item = BlockID>) -> Vec<u8> {
+    ids.flat_map(|id| (id.index() as BlockIndex).to_ne_bytes()).collect()
+}
+
+///creates device local 3d image and fills it with data (order is X -> Y -> Z, texels in format)
+fn upload_image_3d(
+    memory_allocator: Arc<dyn MemoryAllocator>,
+    command_buffer_allocator: &StandardCommandBufferAllocator,
+    queue: &Arc<Queue>,
+    format: Format,
+    extent: [u32; 3],
+    data: Vec<u8>,
+) -> Arc<ImageView> {
+    let staging = Buffer::from_iter(
+        memory_allocator.clone(),
+        BufferCreateInfo {
+            usage: BufferUsage::TRANSFER_SRC,
+            ..Default::default()
+        },
+        AllocationCreateInfo {
+            memory_type_filter: MemoryTypeFilter::PREFER_HOST
+                | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+            ..Default::default()
+        },
+        data,
+    ).unwrap();
+
+    let image = Image::new(memory_allocator, ImageCreateInfo {
+        image_type: ImageType::Dim3d,
+        format,
+        extent,
+        usage: ImageUsage::SAMPLED | ImageUsage::TRANSFER_DST,
+        ..Default::default()
+    }, AllocationCreateInfo::default()).unwrap();
+
+    let mut cbb = AutoCommandBufferBuilder::primary(
+        command_buffer_allocator,
+        queue.queue_family_index(),
+        CommandBufferUsage::OneTimeSubmit,
+    )
+    .unwrap();
+    cbb.copy_buffer_to_image(CopyBufferToImageInfo::buffer_image(staging, image.clone()))
+        .unwrap();
+    cbb.build().unwrap()
+        .execute(queue.clone())
+        .unwrap()
+        .then_signal_fence_and_flush()
+        .unwrap()
+        .wait(None /* timeout */)
+        .unwrap();
+
+    ImageView::new_default(image).unwrap()
+}
+
+///uploads loaded chunks, block_palette and voxel_palette of world
+/// fails if world has more blocks than atlas_capacity
+pub fn upload_volume(
+    memory_allocator: Arc<dyn MemoryAllocator>,
+    command_buffer_allocator: &StandardCommandBufferAllocator,
+    queue: &Arc<Queue>,
+    world: &World,
+) -> Result<VolumeGPU, VolumeError> {
+    let block_count = world.block_count();
+    let capacity = atlas_capacity(queue.device());
+    if block_count > capacity {
+        return Err(VolumeError::TooManyBlocks {blocks: block_count, capacity});
+    }
+
+    let volume = VoxelVolume::new(world);
+    let blocks = block_id_bytes(volume.blocks.iter().copied());
+    let united_blocks_image = upload_image_3d(memory_allocator.clone(), command_buffer_allocator, queue, BLOCK_ID_FORMAT, volume.size.as_uvec3().to_array(), blocks);
+
+    //one spare layer, so new blocks from edits rarely need everything uploaded again
+    let layers = (block_count / ATLAS_LAYER + 1).min(capacity.div_ceil(ATLAS_LAYER));
+    let atlas_extent = [(ATLAS_ROW*BLOCK_SIZE) as u32, (ATLAS_ROW*BLOCK_SIZE) as u32, (layers*BLOCK_SIZE) as u32];
+    let (atlas_w, atlas_h) = (atlas_extent[0] as usize, atlas_extent[1] as usize);
+    let mut atlas = vec![0u8; atlas_w * atlas_h * atlas_extent[2] as usize];
+    for block in 0..block_count {
+        let id = BlockID::from_index(block);
+        let voxels = world.get_block_from_palette(id);
+        let [bx, by, bz] = atlas_offset(id).map(|v| v as usize);
+        for z in 0..BLOCK_SIZE {
+        for y in 0..BLOCK_SIZE {
+            let row = &voxels[(y + z*BLOCK_SIZE)*BLOCK_SIZE..][..BLOCK_SIZE];
+            let start = bx + (by + y)*atlas_w + (bz + z)*atlas_w*atlas_h;
+            for (dst, voxel) in atlas[start..start + BLOCK_SIZE].iter_mut().zip(row) {
+                *dst = voxel.0;
+            }
+        }
+        }
+    }
+    let block_palette_image = upload_image_3d(memory_allocator.clone(), command_buffer_allocator, queue, Format::R8_UINT, atlas_extent, atlas);
+
+    Ok(VolumeGPU {
+        origin: volume.origin,
+        size: volume.size,
+        united_blocks_image,
+        block_palette_image,
+        voxel_palette: upload_palette(memory_allocator, world.voxel_palette.as_slice()),
+    })
+}
+
+///writes changed blocks (World::remesh_dirty output, blocks of streamed chunks) into volume: their ids into united_blocks_image, their voxels into block_palette_image
+/// uploads everything again if loaded area outgrew volume or new blocks do not fit into atlas
+pub fn update_volume(
+    memory_allocator: Arc<dyn MemoryAllocator>,
+    command_buffer_allocator: &StandardCommandBufferAllocator,
+    queue: &Arc<Queue>,
+    volume: &mut VolumeGPU,
+    world: &World,
+    blocks: &[IVec3],
+) -> Result<(), VolumeError> {
+    if blocks.is_empty() {
+        return Ok(());
+    }
+    let (lo, hi) = (volume.origin, volume.origin + volume.size);
+    let fits = world.loaded_bounds().is_none_or(|(min, max)| min.cmpge(lo).all() && max.cmple(hi).all());
+    if !fits || world.block_count() > uploaded_capacity(volume) {
+        *volume = upload_volume(memory_allocator, command_buffer_allocator, queue, world)?;
+        return Ok(());
+    }
+
+    //unloaded chunks read as empty, so their blocks get cleared. Every atlas slot is written once
+    let ids: Vec<BlockID> = blocks.iter().map(|pos| world.loaded_block(*pos)).collect();
+    let mut slots: Vec<BlockID> = ids.iter().copied().filter(|id| !id.is_empty()).collect();
+    slots.sort_by_key(|id| id.index());
+    slots.dedup();
+
+    //staging holds one id per block, then voxels of every slot
+    let mut data = block_id_bytes(ids.iter().copied());
+    for id in &slots {
+        data.extend(world.get_block_from_palette(*id).iter().map(|voxel| voxel.0));
+    }
+    let staging = Buffer::from_iter(
+        memory_allocator,
+        BufferCreateInfo {
+            usage: BufferUsage::TRANSFER_SRC,
+            ..Default::default()
+        },
+        AllocationCreateInfo {
+            memory_type_filter: MemoryTypeFilter::PREFER_HOST
+                | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+            ..Default::default()
+        },
+        data,
+    ).unwrap();
+
+    let united_blocks = volume.united_blocks_image.image().clone();
+    let block_palette = volume.block_palette_image.image().clone();
+    let id_regions = blocks.iter().enumerate().map(|(i, pos)| BufferImageCopy {
+        buffer_offset: (i * size_of::<BlockIndex>()) as DeviceSize,
+        image_subresource: united_blocks.subresource_layers(),
+        image_offset: (*pos - volume.origin).as_uvec3().to_array(),
+        image_extent: [1, 1, 1],
+        ..Default::default()
+    });
+    let voxel_regions = slots.iter().enumerate().map(|(i, id)| BufferImageCopy {
+        buffer_offset: (blocks.len() * size_of::<BlockIndex>() + i*BLOCK_SIZE*BLOCK_SIZE*BLOCK_SIZE) as DeviceSize,
+        buffer_row_length: BLOCK_SIZE as u32,
+        buffer_image_height: BLOCK_SIZE as u32,
+        image_subresource: block_palette.subresource_layers(),
+        image_offset: atlas_offset(*id),
+        image_extent: [BLOCK_SIZE as u32; 3],
+        ..Default::default()
+    });
+
+    let mut cbb = AutoCommandBufferBuilder::primary(
+        command_buffer_allocator,
+        queue.queue_family_index(),
+        CommandBufferUsage::OneTimeSubmit,
+    )
+    .unwrap();
+    cbb.copy_buffer_to_image(CopyBufferToImageInfo {
+            regions: id_regions.collect(),
+            ..CopyBufferToImageInfo::buffer_image(staging.clone(), united_blocks)
+        })
+        .unwrap();
+    //only cleared blocks, nothing to write into atlas
+    if !slots.is_empty() {
+        cbb.copy_buffer_to_image(CopyBufferToImageInfo {
+                regions: voxel_regions.collect(),
+                ..CopyBufferToImageInfo::buffer_image(staging, block_palette)
+            })
+            .unwrap();
+    }
+    cbb.build().unwrap()
+        .execute(queue.clone())
+        .unwrap()
+        .then_signal_fence_and_flush()
+        .unwrap()
+        .wait(None /* timeout */)
+        .unwrap();
+    Ok(())
+}
+
+///has to match push_constant block in trace.comp
+#[derive(BufferContents, Clone, Copy)]
+#[repr(C)]
+pub struct TracePushConstants {
+    ///clip -> world, inverse of Camera::view_proj
+    pub inv_view_proj: [[f32; 4]; 4],
+    ///VolumeGPU::origin, w unused
+    pub volume_origin: [i32; 4],
+    ///VolumeGPU::size, w unused
+    pub volume_size: [i32; 4],
+}
+
+pub fn get_trace_pipeline(device: Arc<Device>, cs: Arc<ShaderModule>) -> Arc<ComputePipeline> {
+    let stage = PipelineShaderStageCreateInfo::new(cs.entry_point("main").unwrap());
+    let layout = PipelineLayout::new(
+        device.clone(),
+        PipelineDescriptorSetLayoutCreateInfo::from_stages([&stage])
+            .into_pipeline_layout_create_info(device.clone())
+            .unwrap(),
+    )
+    .unwrap();
+
+    ComputePipeline::new(device, None, ComputePipelineCreateInfo::stage_layout(stage, layout)).unwrap()
+}
+
+///traces whole target (rgba32f storage image) from camera
+pub fn get_trace_command_buffer(
+    command_buffer_allocator: &StandardCommandBufferAllocator,
+    descriptor_set_allocator: &StandardDescriptorSetAllocator,
+    queue: &Arc<Queue>,
+    pipeline: &Arc<ComputePipeline>,
+    volume: &VolumeGPU,
+    target: Arc<Image>,
+    camera: &Camera,
+) -> Arc<PrimaryAutoCommandBuffer> {
+    let extent = target.extent();
+    //integer images can only be read with nearest filter, which is default
+    let sampler = Sampler::new(pipeline.device().clone(), SamplerCreateInfo::default()).unwrap();
+
+    let set = PersistentDescriptorSet::new(
+        descriptor_set_allocator,
+        pipeline.layout().set_layouts()[0].clone(),
+        [
+            WriteDescriptorSet::buffer(0, volume.voxel_palette.clone()),
+            WriteDescriptorSet::image_view_sampler(1, volume.united_blocks_image.clone(), sampler.clone()),
+            WriteDescriptorSet::image_view_sampler(2, volume.block_palette_image.clone(), sampler),
+            WriteDescriptorSet::image_view(3, ImageView::new_default(target).unwrap()),
+        ],
+        [],
+    ).unwrap();
+
+    let push_constants = TracePushConstants {
+        inv_view_proj: camera.view_proj().inverse().to_cols_array_2d(),
+        volume_origin: volume.origin.extend(0).to_array(),
+        volume_size: volume.size.extend(0).to_array(),
+    };
+
+    let mut builder = AutoCommandBufferBuilder::primary(
+        command_buffer_allocator,
+        queue.queue_family_index(),
+        CommandBufferUsage::MultipleSubmit,
+    )
+    .unwrap();
+    builder
+        .bind_pipeline_compute(pipeline.clone())
+        .unwrap()
+        .bind_descriptor_sets(PipelineBindPoint::Compute, pipeline.layout().clone(), 0, set)
+        .unwrap()
+        .push_constants(pipeline.layout().clone(), 0, push_constants)
+        .unwrap()
+        .dispatch([extent[0].div_ceil(TRACE_GROUP), extent[1].div_ceil(TRACE_GROUP), 1])
+        .unwrap();
+    builder.build().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loader::load_vox;
+    use glam::Vec2;
+    use std::collections::HashSet;
+
+    fn load_world() -> World {
+        let mut world = World::new();
+        world.load_map(&load_vox("assets/scene.vox").unwrap()).unwrap();
+        world
+    }
+
+    ///voxel at pos (in voxels), empty outside of block_bounds
+    fn voxel_at(volume: &impl Volume, pos: IVec3) -> VoxelID {
+        let block_pos = pos.div_euclid(IVec3::splat(BLOCK_SIZE as i32));
+        let (min, max) = volume.block_bounds();
+        if block_pos.cmplt(min).any() || block_pos.cmpge(max).any() {
+            return VoxelID::EMPTY;
+        }
+        volume.voxel(volume.block(block_pos), pos.rem_euclid(IVec3::splat(BLOCK_SIZE as i32)))
+    }
+
+    ///first non-empty voxel found by tiny fixed steps
+    fn march(volume: &impl Volume, ray: &Ray, max_t: f32) -> Option<IVec3> {
+        let mut t = 0.0;
+        while t < max_t {
+            let voxel = ray.at(t).floor().as_ivec3();
+            if !voxel_at(volume, voxel).is_empty() {
+                return Some(voxel);
+            }
+            t += 0.005;
+        }
+        None
+    }
+
+    #[test]
+    fn trace_matches_brute_force() {
+        let world = load_world();
+        let volume = VoxelVolume::new(&world);
+        let (min, max) = world.mesh_bounds().unwrap();
+        let camera = Camera::perspective(max + (max - min) * 0.3, (min + max) / 2.0, 1.0);
+
+        //grid over the view plus rays aimed near mesh vertices, so plenty of them graze edges and corners
+        let mut rays: Vec<Ray> = Vec::new();
+        for y in 0..12 {
+        for x in 0..12 {
+            let (origin, direction) = camera.ray(Vec2::new(x as f32, y as f32) / 5.5 - 1.0);
+            rays.push(Ray::new(origin, direction));
+        }
+        }
+        for chunk in world.chunks.values() {
+            for index in chunk.mesh.indices.iter().step_by(97) {
+                let vertex = &chunk.mesh.vertices[*index as usize];
+                let target = chunk.mesh.trans.transform_point3(Vec3::from(vertex.position)) + Vec3::new(0.3, -0.2, 0.1);
+                rays.push(Ray::new(camera.position, target - camera.position));
+            }
+        }
+
+        let max_t = (max - min).length() * 2.0;
+        let mut hits = 0;
+        for ray in &rays {
+            let expected = march(&volume, ray, max_t);
+            let hit = trace(&volume, ray, max_t);
+            assert_eq!(hit.map(|hit| hit.voxel), expected, "ray {:?}", ray);
+            if let Some(hit) = hit {
+                hits += 1;
+                assert_eq!(hit.id, voxel_at(&volume, hit.voxel));
+                //entered through the face normal points out of
+                let entry = ray.at(hit.t) - hit.voxel.as_vec3() - 0.5;
+                assert!((entry.dot(hit.normal.as_vec3()) - 0.5).abs() < 1e-2, "{:?} {:?}", hit, entry);
+            }
+        }
+        assert!(hits > rays.len() / 4, "only {} of {} rays hit", hits, rays.len());
+    }
+
+    #[test]
+    fn atlas_offsets_do_not_overlap() {
+        let offsets: HashSet<[u32; 3]> = (0..MAX_BLOCKS).map(|b| atlas_offset(BlockID::from_index(b))).collect();
+        assert_eq!(offsets.len(), MAX_BLOCKS);
+        for [x, y, z] in offsets {
+            assert!(x % BLOCK_SIZE as u32 == 0 && y % BLOCK_SIZE as u32 == 0 && z % BLOCK_SIZE as u32 == 0);
+            assert!(x < (ATLAS_ROW*BLOCK_SIZE) as u32 && y < (ATLAS_ROW*BLOCK_SIZE) as u32);
+        }
+    }
+
+    #[test]
+    fn ray_pointing_away_misses() {
+        let world = load_world();
+        let volume = VoxelVolume::new(&world);
+        let (_, max) = world.mesh_bounds().unwrap();
+        assert_eq!(trace(&volume, &Ray::new(max + 10.0, Vec3::ONE), 10000.0), None);
+    }
+}
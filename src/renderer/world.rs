@@ -1,37 +1,47 @@
 extern crate dot_vox;
 
-use std::{boxed, convert::TryInto, mem::MaybeUninit, sync::Arc};
+use std::{collections::{hash_map::DefaultHasher, HashMap, HashSet}, fmt, hash::{Hash, Hasher}};
 
-use block_mesh::{ilattice::glam::{IVec3, Mat4, Vec3, Vec4}, ndshape::ConstShape3u32, GreedyQuadsBuffer, MergeStrategy, MergeVoxel, UnitQuadBuffer, VoxelVisibility, RIGHT_HANDED_Y_UP_CONFIG};
-use glam::UVec4;
+use glam::{IVec3, Mat3, Mat4, Vec3, Vec4};
 // use self::dot_vox::Voxel;
-use vulkano::{buffer::Subbuffer, image::Image};
 
-use crate::{ogt_voxel_meshify::{ogt_mesh_from_paletted_voxels_simple, ogt_mesh_rgba, ogt_voxel_meshify_context}, MyVertex};
+use dot_vox::{DotVoxData, SceneNode};
+
+use crate::{jobs::{MeshJob, MeshWorkers}, loader::VoxLoadError, mesher::{padded_index, BlockMesh, Mesher, PADDED_VOLUME}, tracer::{trace, Ray}, MyVertex};
 
 // use crate::{ogt::{self, ogt_mesh_from_paletted_voxels_greedy, ogt_mesh_from_paletted_voxels_simple, ogt_mesh_rgba, ogt_voxel_meshify_context}, MyVertex};
 // pub(super)
 // ogt
 
-const BLOCK_SIZE: usize = 16;
-const CHUNK_SIZE: usize = 8;
+pub const BLOCK_SIZE: usize = 16;
+const BLOCK_VOLUME: usize = BLOCK_SIZE*BLOCK_SIZE*BLOCK_SIZE;
+pub const CHUNK_SIZE: usize = 8;
+///how many chunks (in each direction) are kept loaded around focus point
 const WORLD_SIZE: usize = 16;
+///what BlockID is stored as, here and on GPU (united_blocks_image follows its size). Change to u8 or u32 to trade memory for block count
+pub type BlockIndex = u16;
+///how many blocks (empty one included) BlockIndex can address. World::max_blocks can only lower it
+pub const MAX_BLOCKS: usize = BlockIndex::MAX as usize + 1;
 
-#[derive(Clone, Copy)]
-pub struct BlockID(u8);
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub struct VoxelID(u8);
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BlockID(BlockIndex);
+///index into World::voxel_palette. 0 is empty, n is .vox palette color n-1 (file index n)
+/// same value is stored in blocks, given to meshers, written into MyVertex::mat and read by shader
+/// transparent so blocks can be handed to ogt as raw u8
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(transparent)]
+pub struct VoxelID(pub u8);
 // #[derive(Debug)]
 pub struct VoxelBlock {
-    ///order is X -> Y -> Z. Each u8 is Material id in palette
+    ///order is X -> Y -> Z. Each u8 is VoxelID
     data: Box<[VoxelID; BLOCK_SIZE*BLOCK_SIZE*BLOCK_SIZE]>,
 }
 #[derive(Clone)]
 pub struct MeshCPU {
     ///vertex data on gpu side
     pub vertices: Vec<MyVertex>,
-    ///index data on gpu side
-    // pub indices: Subbuffer<[u32]>,
+    ///index data on gpu side, 3 per triangle
+    pub indices: Vec<u32>,
     ///rotation, shift and scale it represents
     pub trans: Mat4,
 }
@@ -39,37 +49,91 @@ pub struct VoxelChunk {
     ///order is X -> Y -> Z. Each u8 is VoxelBlock id in palette
     pub mesh: MeshCPU,
     /// is stored on CPU side ONLY because of physics 
-    /// Also stored on GPU for rendering, Changing anything on CPU does not auto-change GPU side
+    /// Also stored on GPU for rendering, changes reach GPU through World::remesh_dirty
     data: Box<[BlockID; CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE]>,
+    ///chunk-local mesh of every block (same order as data), mesh is all of them together
+    /// kept so edits only remesh blocks they touched
+    block_meshes: Vec<BlockMesh>,
 }
 #[derive(Clone, Copy)]
 pub struct Material {
-    color: Vec4,
-    emmitance: f32,
-    roughness: f32,
+    ///rgba in 0..1
+    pub color: Vec4,
+    pub emmitance: f32,
+    pub roughness: f32,
+    pub metalness: f32,
+    ///index of refraction, 1.0 is air
+    pub ior: f32,
+    pub transparency: f32,
 }
 /// All meshes shoult be reflected in world every frame
 /// except for chunks, they are static and reflected on chunk loading (treat this as optimiztion, it could be done every frame but its pointless for now)
 pub struct World {
-    ///represents where chunks[0] starts
-    // pub current_origin: IVec3,
-    ///order is X -> Y -> Z
-    /// currently just one chunk
-    pub block_palette: Box<[VoxelID; 16*16*16*256]>, // 
+    ///chunk coordinate chunks are currently loaded around
+    pub current_origin: IVec3,
+    ///order is X -> Y -> Z. Blocks are deduplicated and shared between chunks, see insert_block
+    /// grows by BLOCK_SIZE^3 with every new block, first one is always empty
+    pub block_palette: Vec<VoxelID>,
     pub voxel_palette: Box<[Material; 256]>,// 256,
-    pub chunks: Box<[VoxelChunk; 1*1*1]>,
-    // GPU-side 3d buffer (image) that stores world with all chunks within it
-    // copied every frame to somewhere and used to conpub struct current voxelated scene world
-    // pub united_blocks_image: Arc<Image>,
-    // 3d array of 16x16x16 x 256 u8 that are material id's representing voxels
-    // pub block_palette_image: Arc<Image>,
-    // 1d array of materials 
-    // pub voxel_palette_image: Arc<Image>,
+    ///loaded chunks, keyed by chunk coordinate. Each one has its own mesh
+    pub chunks: HashMap<IVec3, VoxelChunk>,
+    ///block data of chunks that are not loaded (too far from current_origin). They have no mesh
+    stored_chunks: HashMap<IVec3, Box<[BlockID; CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE]>>,
+    ///used for every block that has no override
+    pub mesher: Mesher,
+    ///per model (index in .vox) mesher override, applied to blocks model is loaded into
+    pub model_meshers: HashMap<usize, Mesher>,
+    block_meshers: HashMap<BlockID, Mesher>,
+    ///how many chunk positions (loaded and stored) use each block_palette slot
+    block_refs: Vec<u32>,
+    ///content hash -> slots with that hash, so identical blocks are stored once
+    block_lookup: HashMap<u64, Vec<BlockID>>,
+    ///slots that are no longer referenced, reused before next_block
+    free_blocks: Vec<BlockID>,
+    ///block_palette will not grow past this many blocks (empty one included), at most MAX_BLOCKS
+    /// set it before loading to what GPU can hold, see tracer::atlas_capacity
+    pub max_blocks: usize,
+    ///blocks (in blocks) edited since last remesh_dirty
+    edited_blocks: HashSet<IVec3>,
+    ///blocks (in blocks) to remesh: edited ones and neighbours their edits can show through (culling, AO)
+    dirty_blocks: HashSet<IVec3>,
+    ///mesh chunks and dirty blocks on these threads instead of in place, meshes show up in later remesh_dirty calls
+    pub workers: Option<MeshWorkers>,
+    ///latest job of every block workers are meshing, results of older ones are dropped
+    pending_meshes: HashMap<IVec3, u64>,
+    next_job: u64,
+    // GPU side (united_blocks_image, block_palette_image, voxel_palette) lives in tracer::VolumeGPU
+}
+impl VoxelID {
+    pub const EMPTY: VoxelID = VoxelID(0);
+
+    ///from index into DotVoxData::palette (dot_vox Voxel::i, 0..=254)
+    pub fn from_palette_index(index: u8) -> VoxelID {
+        VoxelID(index + 1)
+    }
+    pub fn is_empty(self) -> bool {
+        self == VoxelID::EMPTY
+    }
+}
+impl BlockID {
+    pub const EMPTY: BlockID = BlockID(0);
+
+    pub fn is_empty(self) -> bool {
+        self == BlockID::EMPTY
+    }
+    ///which block of block_palette it is
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+    pub(crate) fn from_index(index: usize) -> BlockID {
+        assert!(index < MAX_BLOCKS, "block index {} does not fit into BlockID", index);
+        BlockID(index as BlockIndex)
+    }
 }
 impl VoxelBlock {
     //sets to Zero
     fn new() -> Self {
-        VoxelBlock {data: Box::new([VoxelID(0); 16*16*16])}
+        VoxelBlock {data: Box::new([VoxelID::EMPTY; 16*16*16])}
     }
 //     //order is X -> Y -> Z
 //     fn get(&self, x: usize, y: usize, z: usize) -> VoxelID {
@@ -84,10 +148,26 @@ impl VoxelBlock {
 }
 impl VoxelChunk {
     ///order is X -> Y -> Z
-    fn new(mesh: MeshCPU, data: Box<[BlockID; CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE]>) -> Self {
-        VoxelChunk {
-            mesh: mesh,
-            data: data
+    fn new(chunk_pos: IVec3, data: Box<[BlockID; CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE]>, block_meshes: Vec<BlockMesh>) -> Self {
+        let mut chunk = VoxelChunk {
+            mesh: MeshCPU {
+                vertices: Vec::new(),
+                indices: Vec::new(),
+                trans: Mat4::from_translation(chunk_pos.as_vec3() * (CHUNK_SIZE*BLOCK_SIZE) as f32),
+            },
+            data,
+            block_meshes,
+        };
+        chunk.rebuild_mesh();
+        chunk
+    }
+    fn rebuild_mesh(&mut self) {
+        self.mesh.vertices.clear();
+        self.mesh.indices.clear();
+        for block in &self.block_meshes {
+            let start = self.mesh.vertices.len() as u32;
+            self.mesh.vertices.extend_from_slice(&block.vertices);
+            self.mesh.indices.extend(block.indices.iter().map(|i| start + i));
         }
     }
     fn get(&self, x: usize, y: usize, z: usize) -> BlockID {
@@ -99,164 +179,1025 @@ impl VoxelChunk {
         self.data[index] = value;
     }
 }
-impl block_mesh::Voxel for VoxelID {
-    fn get_visibility(&self) -> VoxelVisibility {
-        if *self == VoxelID(0) {
-            VoxelVisibility::Empty
-        } else {
-            VoxelVisibility::Opaque
-        }
+///what changed after World::stream_around, so renderer can upload / free GPU side of chunks
+pub struct ChunkChanges {
+    pub loaded: Vec<IVec3>,
+    pub unloaded: Vec<IVec3>,
+}
+impl ChunkChanges {
+    pub fn is_empty(&self) -> bool {
+        self.loaded.is_empty() && self.unloaded.is_empty()
+    }
+}
+///what World::remesh_dirty touched, so renderer can reupload only that
+pub struct Remeshed {
+    ///loaded chunks with new mesh
+    pub chunks: Vec<IVec3>,
+    ///edited blocks (in blocks) of loaded chunks
+    pub blocks: Vec<IVec3>,
+}
+impl Remeshed {
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty() && self.blocks.is_empty()
     }
 }
-impl block_mesh::MergeVoxel for VoxelID {
-    type MergeValue = Self;
 
-    fn merge_value(&self) -> Self::MergeValue {
-        VoxelID(255)
-        // *self
+///what World::edit_at does to voxel ray hits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoxelEdit {
+    ///clears hit voxel
+    Remove,
+    ///puts voxel of the same id onto face ray entered hit voxel through
+    Place,
+}
+
+#[derive(Debug)]
+pub enum EditError {
+    ///block_palette already has max_blocks blocks and none of them is free, so edited block has nowhere to go
+    BlockPaletteFull { limit: usize },
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EditError::BlockPaletteFull { limit } => write!(f, "block palette is full ({} blocks)", limit),
+        }
     }
 }
-//TODO MAKE DYNAMIC
-type BlockShape = ConstShape3u32<18, 18, 18>;
+
+impl std::error::Error for EditError {}
 
 impl World {
     pub fn new() -> World{
         World {
-            block_palette: Box::new([VoxelID(0); 16*16*16*256]),
+            current_origin: IVec3::ZERO,
+            //0 is empty block
+            block_palette: vec![VoxelID::EMPTY; BLOCK_VOLUME],
             voxel_palette: Box::new([
                 Material {
                     color: Vec4::new(0.0,0.0,0.0,0.0), 
                     emmitance: 0.0, 
-                    roughness: 0.0};
+                    roughness: 0.0,
+                    metalness: 0.0,
+                    ior: 1.0,
+                    transparency: 0.0};
                 256]),
-            chunks: Box::new([VoxelChunk {
-                mesh: MeshCPU {
-                    vertices: Vec::new(),
-                    trans: Mat4::IDENTITY,
-                },
-                data: Box::new([BlockID(0); CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE]),
-            }; 1])
+            chunks: HashMap::new(),
+            stored_chunks: HashMap::new(),
+            mesher: Mesher::OgtSimple,
+            model_meshers: HashMap::new(),
+            block_meshers: HashMap::new(),
+            block_refs: vec![0],
+            block_lookup: HashMap::new(),
+            free_blocks: Vec::new(),
+            max_blocks: MAX_BLOCKS,
+            edited_blocks: HashSet::new(),
+            dirty_blocks: HashSet::new(),
+            workers: None,
+            pending_meshes: HashMap::new(),
+            next_job: 0,
         }
     }
 
-    /// order is X -> Y -> Z
-    /// 
-    fn set_block_in_palette(&mut self, block: VoxelBlock, id: usize) {
-        for x in 0..16{
-        for y in 0..16{
-        for z in 0..16{
-            let real_z = z + id*16;
-            self.block_palette[x + 16*y + 16*16*real_z] = block.data[x + 16*y + 16*16*z];
+    fn hash_block(voxels: &[VoxelID]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        voxels.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    ///id of block with these voxels (order is X -> Y -> Z), reused if identical one is already in palette
+    /// takes one reference, give it back with release_block. All-empty block is BlockID::EMPTY
+    fn insert_block(&mut self, voxels: &[VoxelID]) -> Result<BlockID, EditError> {
+        if voxels.iter().all(|voxel| voxel.is_empty()) {
+            return Ok(BlockID::EMPTY);
         }
+        let hash = World::hash_block(voxels);
+        let existing = self.block_lookup.get(&hash)
+            .and_then(|ids| ids.iter().copied().find(|id| self.get_block_from_palette(*id) == voxels));
+        let id = match existing {
+            Some(id) => id,
+            None => {
+                let id = match self.free_blocks.pop() {
+                    Some(id) => id,
+                    None if self.block_count() < self.max_blocks.min(MAX_BLOCKS) => {
+                        self.block_palette.resize(self.block_palette.len() + BLOCK_VOLUME, VoxelID::EMPTY);
+                        self.block_refs.push(0);
+                        BlockID::from_index(self.block_count() - 1)
+                    }
+                    None => return Err(EditError::BlockPaletteFull {limit: self.max_blocks.min(MAX_BLOCKS)}),
+                };
+                self.block_palette[id.index()*BLOCK_VOLUME..][..BLOCK_VOLUME].copy_from_slice(voxels);
+                self.block_lookup.entry(hash).or_default().push(id);
+                id
+            }
+        };
+        self.block_refs[id.index()] += 1;
+        Ok(id)
+    }
+
+    ///gives back reference taken by insert_block. Unreferenced slot is freed for reuse
+    fn release_block(&mut self, id: BlockID) {
+        if id.is_empty() {
+            return;
+        }
+        self.block_refs[id.index()] -= 1;
+        if self.block_refs[id.index()] > 0 {
+            return;
         }
+        let hash = World::hash_block(self.get_block_from_palette(id));
+        if let Some(ids) = self.block_lookup.get_mut(&hash) {
+            ids.retain(|other| *other != id);
+            if ids.is_empty() {
+                self.block_lookup.remove(&hash);
+            }
         }
+        self.block_meshers.remove(&id);
+        self.free_blocks.push(id);
     }
 
-    pub fn load_map(&mut self){
-        // println!("lmao");
-        let scene = dot_vox::load("assets/scene.vox").unwrap();
-        // scene.materials
+    ///voxels of block with given id, order is X -> Y -> Z
+    pub(crate) fn get_block_from_palette(&self, id: BlockID) -> &[VoxelID] {
+        &self.block_palette[id.index()*BLOCK_VOLUME .. (id.index()+1)*BLOCK_VOLUME]
+    }
 
-        let mut block_id = 1;
-        for model in &scene.models {
-            let mut current_block = VoxelBlock::new(); //zeroed
-            //used as temporary storage for meshification
-            let mut temp_block = [VoxelID(0); 18*18*18];
+    ///blocks in block_palette, free and empty ones included. Every BlockID in world is below it
+    pub fn block_count(&self) -> usize {
+        self.block_palette.len() / BLOCK_VOLUME
+    }
 
-            for voxel in &model.voxels{
-                let x = voxel.x as usize;
-                let y = voxel.y as usize;
-                let z = voxel.z as usize;
-                current_block.data[ x + BLOCK_SIZE*y + BLOCK_SIZE*BLOCK_SIZE*z   ] = VoxelID(voxel.i+1);
-                   temp_block     [(x+1) +     18*(y+1) +             18*18*(z+1)] = VoxelID(voxel.i);
+    ///chunk that contains block at pos (in blocks) and block position inside of it
+    fn split_block_pos(pos: IVec3) -> (IVec3, IVec3) {
+        let chunk_size = CHUNK_SIZE as i32;
+        (pos.div_euclid(IVec3::splat(chunk_size)), pos.rem_euclid(IVec3::splat(chunk_size)))
+    }
+
+    ///every block position (in blocks) inside of chunk at chunk_pos, order X -> Y -> Z
+    pub fn chunk_blocks(chunk_pos: IVec3) -> impl Iterator<Item = IVec3> {
+        let chunk_size = CHUNK_SIZE as i32;
+        (0..chunk_size.pow(3)).map(move |i| chunk_pos * chunk_size + IVec3::new(i % chunk_size, i / chunk_size % chunk_size, i / (chunk_size*chunk_size)))
+    }
+
+    ///chunk that contains point pos (in voxels), what stream_around should be given to keep pos in the middle of loaded world
+    pub fn chunk_at(pos: Vec3) -> IVec3 {
+        (pos / (CHUNK_SIZE*BLOCK_SIZE) as f32).floor().as_ivec3()
+    }
+
+    ///sets block at pos (in blocks) to id, which has to be referenced already (see insert_block). Creates chunk if there is none
+    /// releases block that was there before. Does not remesh loaded chunks
+    fn set_block(&mut self, pos: IVec3, id: BlockID) {
+        let (chunk_pos, local) = World::split_block_pos(pos);
+        let (x, y, z) = (local.x as usize, local.y as usize, local.z as usize);
+
+        let old = if let Some(chunk) = self.chunks.get_mut(&chunk_pos) {
+            let old = chunk.get(x, y, z);
+            chunk.set(x, y, z, id);
+            old
+        } else {
+            let data = self.stored_chunks
+                .entry(chunk_pos)
+                .or_insert_with(|| Box::new([BlockID(0); CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE]));
+            std::mem::replace(&mut data[x + y*CHUNK_SIZE + z*CHUNK_SIZE*CHUNK_SIZE], id)
+        };
+        self.release_block(old);
+    }
+
+    ///voxels of block at pos (in blocks) with one voxel of every neighbouring block (loaded or not) around, see mesher::padded_index
+    fn padded_block(&self, pos: IVec3) -> Vec<VoxelID> {
+        let mut neighbours = [BlockID::EMPTY; 27];
+        for (i, neighbour) in neighbours.iter_mut().enumerate() {
+            let offset = IVec3::new(i as i32 % 3, i as i32 / 3 % 3, i as i32 / 9) - IVec3::ONE;
+            *neighbour = self.get_block(pos + offset);
+        }
+        let block_size = IVec3::splat(BLOCK_SIZE as i32);
+        let mut padded = vec![VoxelID::EMPTY; PADDED_VOLUME];
+        for z in -1..=BLOCK_SIZE as i32 {
+        for y in -1..=BLOCK_SIZE as i32 {
+        for x in -1..=BLOCK_SIZE as i32 {
+            let local = IVec3::new(x, y, z);
+            let offset = local.div_euclid(block_size) + IVec3::ONE;
+            let block = neighbours[(offset.x + offset.y*3 + offset.z*9) as usize];
+            if block.is_empty() {
+                continue;
             }
-            
-            // let mut current_buffer = UnitQuadBuffer::new();
-            let mut current_buffer = GreedyQuadsBuffer::new(temp_block.len());
-            block_mesh::greedy_quads(&temp_block, &BlockShape{}, [0; 3], [17; 3], &RIGHT_HANDED_Y_UP_CONFIG.faces, &mut current_buffer);
-            // block_mesh::visible_block_faces(&temp_block, &BlockShape{}, [0; 3], [17; 3], &RIGHT_HANDED_Y_UP_CONFIG.faces, &mut current_buffer);
-            
-            // println!("{}", current_buffer.quads.num_quads());
-            
-            for face_i in 0..6{
-                let face_dir = &RIGHT_HANDED_Y_UP_CONFIG.faces[face_i];
-                let face_group = &current_buffer.quads.groups[face_i];
-                // let face_group = &current_buffer.groups[face_i];
-                
-                let normals = face_dir.quad_mesh_normals();
-                
-                for &quad in face_group{
-                    // let positions = face_dir.quad_mesh_positions(&quad.into(), 1.0);
-                    let corners = face_dir.quad_corners(&quad);
-                    let positions = face_dir.quad_mesh_positions(&quad, 1.0);
-                    // let corners = face_dir.quad_corners(&quad.into());
-
-                    let mut mats = [VoxelID(0); 4];
-                    for i in 0..4{
-                        let x = (corners[i].x) as usize;
-                        let y = (corners[i].y) as usize;
-                        let z = (corners[i].z) as usize;
-                        mats[i] = temp_block[x + 18*y + 18*18*z];
-                     // print!("{} ", corners[i]);
-                    }
-                    // println!();
-                    
-                    self.chunks[0].mesh.vertices.push(MyVertex {position: positions[0  ], normal: normals[0  ], mat: mats[0  ].0});
-                    self.chunks[0].mesh.vertices.push(MyVertex {position: positions[1  ], normal: normals[1  ], mat: mats[1  ].0});
-                    self.chunks[0].mesh.vertices.push(MyVertex {position: positions[2  ], normal: normals[2  ], mat: mats[2  ].0});
-                    self.chunks[0].mesh.vertices.push(MyVertex {position: positions[1+0], normal: normals[1+0], mat: mats[1+0].0});
-                    self.chunks[0].mesh.vertices.push(MyVertex {position: positions[1+1], normal: normals[1+1], mat: mats[1+1].0});
-                    self.chunks[0].mesh.vertices.push(MyVertex {position: positions[1+2], normal: normals[1+2], mat: mats[1+2].0});
+            let voxel = local.rem_euclid(block_size);
+            padded[padded_index(local)] = self.get_block_from_palette(block)[voxel.x as usize + voxel.y as usize*BLOCK_SIZE + voxel.z as usize*BLOCK_SIZE*BLOCK_SIZE];
+        }
+        }
+        }
+        padded
+    }
+
+    ///mesher and padded voxels of block at pos (in blocks), None if block is empty and has no mesh
+    fn mesh_input(&self, pos: IVec3) -> Option<(Mesher, Vec<VoxelID>)> {
+        let block_id = self.get_block(pos);
+        if block_id.is_empty() {
+            return None;
+        }
+        let mesher = *self.block_meshers.get(&block_id).unwrap_or(&self.mesher);
+        Some((mesher, self.padded_block(pos)))
+    }
+
+    ///moves block-local mesh of block at pos (in blocks) into its chunk
+    fn to_chunk_space(pos: IVec3, mut mesh: BlockMesh) -> BlockMesh {
+        let (_, local) = World::split_block_pos(pos);
+        let shift = local.as_vec3() * BLOCK_SIZE as f32;
+        for vertex in &mut mesh.vertices {
+            vertex.position = (Vec3::from(vertex.position) + shift).into();
+        }
+        mesh
+    }
+
+    ///chunk-local mesh of block at pos (in blocks), faces hidden by neighbouring blocks are culled
+    fn mesh_block(&self, pos: IVec3) -> BlockMesh {
+        match self.mesh_input(pos) {
+            Some((mesher, padded)) => World::to_chunk_space(pos, mesher.mesh_block(&padded)),
+            None => BlockMesh::default(),
+        }
+    }
+
+    ///hands block at pos (in blocks) to workers, empty one gets its (empty) mesh right away
+    /// true if mesh of its chunk changed already
+    fn queue_block(&mut self, pos: IVec3) -> bool {
+        let Some((mesher, padded)) = self.mesh_input(pos) else {
+            self.pending_meshes.remove(&pos);
+            let (chunk_pos, local) = World::split_block_pos(pos);
+            let index = local.x as usize + local.y as usize*CHUNK_SIZE + local.z as usize*CHUNK_SIZE*CHUNK_SIZE;
+            let Some(chunk) = self.chunks.get_mut(&chunk_pos) else {return false;};
+            let had_mesh = !chunk.block_meshes[index].indices.is_empty();
+            chunk.block_meshes[index] = BlockMesh::default();
+            return had_mesh;
+        };
+        self.next_job += 1;
+        self.pending_meshes.insert(pos, self.next_job);
+        self.workers.as_ref().unwrap().submit(MeshJob {block_pos: pos, id: self.next_job, mesher, padded});
+        false
+    }
+
+    ///blocks still being meshed by workers
+    pub fn meshing(&self) -> usize {
+        self.pending_meshes.len()
+    }
+
+    ///meshes of every block of chunk at chunk_pos, in data order
+    fn mesh_chunk(&self, chunk_pos: IVec3) -> Vec<BlockMesh> {
+        (0..CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE).map(|i| {
+            let local = IVec3::new((i % CHUNK_SIZE) as i32, (i / CHUNK_SIZE % CHUNK_SIZE) as i32, (i / (CHUNK_SIZE*CHUNK_SIZE)) as i32);
+            self.mesh_block(chunk_pos * CHUNK_SIZE as i32 + local)
+        }).collect()
+    }
+
+    ///block at pos (in blocks), empty if its chunk is not loaded
+    pub fn loaded_block(&self, pos: IVec3) -> BlockID {
+        let (chunk_pos, local) = World::split_block_pos(pos);
+        self.chunks.get(&chunk_pos).map_or(BlockID::EMPTY, |chunk| chunk.get(local.x as usize, local.y as usize, local.z as usize))
+    }
+
+    ///voxels of loaded chunk at chunk_pos as one volume of side CHUNK_SIZE*BLOCK_SIZE, order X -> Y -> Z. None if it is not loaded
+    pub fn chunk_voxels(&self, chunk_pos: IVec3) -> Option<Vec<VoxelID>> {
+        const SIDE: usize = CHUNK_SIZE*BLOCK_SIZE;
+        let chunk = self.chunks.get(&chunk_pos)?;
+        let mut voxels = vec![VoxelID::EMPTY; SIDE*SIDE*SIDE];
+        for (i, id) in chunk.data.iter().enumerate() {
+            if id.is_empty() {
+                continue;
+            }
+            let block = self.get_block_from_palette(*id);
+            let min = [i % CHUNK_SIZE, i / CHUNK_SIZE % CHUNK_SIZE, i / (CHUNK_SIZE*CHUNK_SIZE)].map(|c| c * BLOCK_SIZE);
+            for z in 0..BLOCK_SIZE {
+            for y in 0..BLOCK_SIZE {
+                let row = &block[y*BLOCK_SIZE + z*BLOCK_SIZE*BLOCK_SIZE..][..BLOCK_SIZE];
+                let start = min[0] + (min[1] + y)*SIDE + (min[2] + z)*SIDE*SIDE;
+                voxels[start..start + BLOCK_SIZE].copy_from_slice(row);
+            }
+            }
+        }
+        Some(voxels)
+    }
+
+    ///min (inclusive) and max (exclusive) block position covered by loaded chunks
+    pub fn loaded_bounds(&self) -> Option<(IVec3, IVec3)> {
+        let min = self.chunks.keys().copied().reduce(IVec3::min)?;
+        let max = self.chunks.keys().copied().reduce(IVec3::max)?;
+        Some((min * CHUNK_SIZE as i32, (max + 1) * CHUNK_SIZE as i32))
+    }
+
+    ///world space min and max of every loaded chunk mesh, None if there is nothing to draw
+    pub fn mesh_bounds(&self) -> Option<(Vec3, Vec3)> {
+        self.chunks.values()
+            .flat_map(|chunk| chunk.mesh.vertices.iter().map(move |v| chunk.mesh.trans.transform_point3(Vec3::from(v.position))))
+            .fold(None, |bounds, p| match bounds {
+                None => Some((p, p)),
+                Some((min, max)) => Some((min.min(p), max.max(p))),
+            })
+    }
+
+    ///world space min and max of every non-empty loaded block, e.g. while workers have not meshed them yet
+    pub fn block_bounds(&self) -> Option<(Vec3, Vec3)> {
+        self.chunks.iter()
+            .flat_map(|(chunk_pos, chunk)| chunk.data.iter().enumerate()
+                .filter(|(_, id)| !id.is_empty())
+                .map(move |(i, _)| *chunk_pos * CHUNK_SIZE as i32 + IVec3::new((i % CHUNK_SIZE) as i32, (i / CHUNK_SIZE % CHUNK_SIZE) as i32, (i / (CHUNK_SIZE*CHUNK_SIZE)) as i32)))
+            .fold(None, |bounds, pos| match bounds {
+                None => Some((pos, pos)),
+                Some((min, max)) => Some((pos.min(min), pos.max(max))),
+            })
+            .map(|(min, max)| ((min * BLOCK_SIZE as i32).as_vec3(), ((max + 1) * BLOCK_SIZE as i32).as_vec3()))
+    }
+
+    ///rebuilds mesh of loaded chunk (e.g. after its blocks changed) in place, even with workers
+    pub fn remesh_chunk(&mut self, chunk_pos: IVec3) {
+        if self.chunks.contains_key(&chunk_pos) {
+            //whatever workers still do for it would only be older
+            self.pending_meshes.retain(|block_pos, _| World::split_block_pos(*block_pos).0 != chunk_pos);
+            let block_meshes = self.mesh_chunk(chunk_pos);
+            let chunk = self.chunks.get_mut(&chunk_pos).unwrap();
+            chunk.block_meshes = block_meshes;
+            chunk.rebuild_mesh();
+        }
+    }
+
+    ///remeshes blocks edited since last call and neighbours touching the edits (only them, not whole chunks)
+    /// blocks of chunks that are not loaded are meshed when chunk gets loaded
+    /// with workers dirty blocks are only queued, chunks are the ones whose finished meshes arrived since last call
+    pub fn remesh_dirty(&mut self) -> Remeshed {
+        let mut chunks = HashSet::new();
+        for block_pos in std::mem::take(&mut self.dirty_blocks) {
+            let (chunk_pos, local) = World::split_block_pos(block_pos);
+            if !self.chunks.contains_key(&chunk_pos) {
+                continue;
+            }
+            if self.workers.is_some() {
+                if self.queue_block(block_pos) {
+                    chunks.insert(chunk_pos);
                 }
+                continue;
+            }
+            let index = local.x as usize + local.y as usize*CHUNK_SIZE + local.z as usize*CHUNK_SIZE*CHUNK_SIZE;
+            let mesh = self.mesh_block(block_pos);
+            self.chunks.get_mut(&chunk_pos).unwrap().block_meshes[index] = mesh;
+            chunks.insert(chunk_pos);
+        }
+        let finished: Vec<_> = self.workers.iter().flat_map(|workers| workers.finished()).collect();
+        for done in finished {
+            //block was edited (or its chunk unloaded) after this job was sent
+            if self.pending_meshes.get(&done.block_pos) != Some(&done.id) {
+                continue;
+            }
+            self.pending_meshes.remove(&done.block_pos);
+            let (chunk_pos, local) = World::split_block_pos(done.block_pos);
+            let index = local.x as usize + local.y as usize*CHUNK_SIZE + local.z as usize*CHUNK_SIZE*CHUNK_SIZE;
+            //worker ran out of ogt memory it may take, main thread heap has no limit
+            let mesh = match done.mesh {
+                Ok(mesh) => World::to_chunk_space(done.block_pos, mesh),
+                Err(_) => self.mesh_block(done.block_pos),
             };
-            self.set_block_in_palette(current_block, block_id);
-            block_id+=1;
+            self.chunks.get_mut(&chunk_pos).unwrap().block_meshes[index] = mesh;
+            chunks.insert(chunk_pos);
+        }
+        let blocks = std::mem::take(&mut self.edited_blocks).into_iter()
+            .filter(|pos| self.chunks.contains_key(&World::split_block_pos(*pos).0))
+            .collect();
+        for chunk_pos in &chunks {
+            self.chunks.get_mut(chunk_pos).unwrap().rebuild_mesh();
+        }
+        Remeshed {chunks: chunks.into_iter().collect(), blocks}
+    }
+
+    ///block (in blocks) and voxel inside of it that contain voxel at pos (in voxels)
+    fn split_voxel_pos(pos: IVec3) -> (IVec3, usize) {
+        let block_size = IVec3::splat(BLOCK_SIZE as i32);
+        let local = pos.rem_euclid(block_size);
+        (pos.div_euclid(block_size), local.x as usize + local.y as usize*BLOCK_SIZE + local.z as usize*BLOCK_SIZE*BLOCK_SIZE)
+    }
+
+    ///block at pos (in blocks), loaded or not
+    fn get_block(&self, pos: IVec3) -> BlockID {
+        let (chunk_pos, local) = World::split_block_pos(pos);
+        let index = local.x as usize + local.y as usize*CHUNK_SIZE + local.z as usize*CHUNK_SIZE*CHUNK_SIZE;
+        match self.chunks.get(&chunk_pos) {
+            Some(chunk) => chunk.data[index],
+            None => self.stored_chunks.get(&chunk_pos).map_or(BlockID::EMPTY, |data| data[index]),
+        }
+    }
+
+    ///voxel at pos (in voxels, world space), loaded or not
+    pub fn get_voxel(&self, pos: IVec3) -> VoxelID {
+        let (block_pos, index) = World::split_voxel_pos(pos);
+        self.get_block_from_palette(self.get_block(block_pos))[index]
+    }
+
+    ///sets voxel at pos (in voxels, world space) and marks its block (and neighbours touching pos) dirty, see remesh_dirty
+    /// block is copied on write, so other places sharing it stay as they were
+    pub fn set_voxel(&mut self, pos: IVec3, voxel: VoxelID) -> Result<(), EditError> {
+        //nothing to copy or remesh
+        if self.get_voxel(pos) == voxel {
+            return Ok(());
+        }
+        let (block_pos, index) = World::split_voxel_pos(pos);
+        let old_id = self.get_block(block_pos);
+        let mut voxels = self.get_block_from_palette(old_id).to_vec();
+        voxels[index] = voxel;
+
+        //released first, so block used only here can give its slot to the edited copy
+        let mesher = self.block_meshers.get(&old_id).copied();
+        self.release_block(old_id);
+        let new_id = match self.insert_block(&voxels) {
+            Ok(id) => id,
+            Err(e) => {
+                //old block was shared (or there would be free slot now), so it is still intact
+                if !old_id.is_empty() {
+                    self.block_refs[old_id.index()] += 1;
+                }
+                return Err(e);
+            }
         };
+        if let Some(mesher) = mesher {
+            self.block_meshers.entry(new_id).or_insert(mesher);
+        }
+
+        //new chunk near focus has to be loaded right away, otherwise edit would not show up
+        let (chunk_pos, local) = World::split_block_pos(block_pos);
+        if World::in_range(self.current_origin, chunk_pos) && !self.chunks.contains_key(&chunk_pos) && !self.stored_chunks.contains_key(&chunk_pos) {
+            let data = Box::new([BlockID::EMPTY; CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE]);
+            self.chunks.insert(chunk_pos, VoxelChunk::new(chunk_pos, data, vec![BlockMesh::default(); CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE]));
+        }
+        //reference was already given back above
+        let index = local.x as usize + local.y as usize*CHUNK_SIZE + local.z as usize*CHUNK_SIZE*CHUNK_SIZE;
+        match self.chunks.get_mut(&chunk_pos) {
+            Some(chunk) => chunk.data[index] = new_id,
+            None => self.stored_chunks.entry(chunk_pos)
+                .or_insert_with(|| Box::new([BlockID::EMPTY; CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE]))[index] = new_id,
+        }
+        self.edited_blocks.insert(block_pos);
+        //voxel hides faces and darkens corners of every voxel around it, those can be in other blocks
+        for i in 0..27 {
+            let offset = IVec3::new(i % 3, i / 3 % 3, i / 9) - IVec3::ONE;
+            self.dirty_blocks.insert((pos + offset).div_euclid(IVec3::splat(BLOCK_SIZE as i32)));
+        }
+        Ok(())
     }
 
-    pub fn load_map_ogt(&mut self){
-        let scene = dot_vox::load("assets/scene.vox").unwrap();
-        //we dont need colors directly so initialization is unnesessary. We'll just use material index from resulting mesh
-        let mut ogt_palette = [ogt_mesh_rgba {r:4,g:3,b:2,a:1}; 256];
-        
+    ///does edit at first loaded voxel ray hits (see tracer::trace), like set_voxel
+    /// returns voxel that was set, None if ray hits nothing or starts inside of voxel (there is no face to place onto)
+    pub fn edit_at(&mut self, ray: &Ray, edit: VoxelEdit) -> Result<Option<IVec3>, EditError> {
+        let Some(hit) = trace(self, ray, f32::INFINITY) else {return Ok(None);};
+        let (pos, voxel) = match edit {
+            VoxelEdit::Remove => (hit.voxel, VoxelID::EMPTY),
+            VoxelEdit::Place if hit.normal == IVec3::ZERO => return Ok(None),
+            VoxelEdit::Place => (hit.voxel + hit.normal, hit.id),
+        };
+        self.set_voxel(pos, voxel)?;
+        Ok(Some(pos))
+    }
 
-        for model in &scene.models {
-            let mut current_block = VoxelBlock::new(); //zeroed
-            
-            for voxel in &model.voxels{
-                let x = voxel.x as usize;
-                let y = voxel.y as usize;
-                let z = voxel.z as usize;
-                current_block.data[ x + BLOCK_SIZE*y + BLOCK_SIZE*BLOCK_SIZE*z] = VoxelID(voxel.i+1);
+    ///is chunk within WORLD_SIZE/2 of focus
+    fn in_range(focus: IVec3, chunk_pos: IVec3) -> bool {
+        (chunk_pos - focus).abs().max_element() <= (WORLD_SIZE / 2) as i32
+    }
+
+    ///loads (and meshes, or queues for workers) every chunk within WORLD_SIZE/2 of focus and unloads ones outside of it
+    pub fn stream_around(&mut self, focus: IVec3) -> ChunkChanges {
+        self.current_origin = focus;
+        let in_range = |pos: &IVec3| World::in_range(focus, *pos);
+
+        let unloaded: Vec<IVec3> = self.chunks.keys().filter(|pos| !in_range(pos)).copied().collect();
+        for pos in &unloaded {
+            let chunk = self.chunks.remove(pos).unwrap();
+            self.stored_chunks.insert(*pos, chunk.data);
+        }
+        self.pending_meshes.retain(|block_pos, _| in_range(&World::split_block_pos(*block_pos).0));
+
+        //neighbours are read from stored and loaded chunks alike, so (un)loading never changes meshes of other chunks
+        let loaded: Vec<IVec3> = self.stored_chunks.keys().filter(|pos| in_range(pos)).copied().collect();
+        for pos in &loaded {
+            let data = self.stored_chunks.remove(pos).unwrap();
+            self.chunks.insert(*pos, VoxelChunk::new(*pos, data, vec![BlockMesh::default(); CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE]));
+        }
+        for pos in &loaded {
+            if self.workers.is_none() {
+                self.remesh_chunk(*pos);
+                continue;
             }
-            let mut res;
-println!("lmao");
-            unsafe {
-                let ctx: ogt_voxel_meshify_context = MaybeUninit::zeroed().assume_init();
-                res =  ogt_mesh_from_paletted_voxels_simple(&ctx, current_block.data.as_ptr() as *const u8, 16, 16, 16, ogt_palette.as_ptr() as *const ogt_mesh_rgba);
-                assert!(res != std::ptr::null_mut());
+            for i in 0..CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE {
+                let local = IVec3::new((i % CHUNK_SIZE) as i32, (i / CHUNK_SIZE % CHUNK_SIZE) as i32, (i / (CHUNK_SIZE*CHUNK_SIZE)) as i32);
+                self.queue_block(*pos * CHUNK_SIZE as i32 + local);
             }
-println!("lmao");
-            for i in 0..(unsafe { *res }).index_count {
-                let vertex_index = unsafe { *(unsafe { *res }).indices.wrapping_add(i as usize) };
-                println!("{}", vertex_index);
-                self.chunks[0].mesh.vertices.push(MyVertex {
-                    position: {[
-                        (unsafe { *(unsafe { *res }).vertices.wrapping_add(vertex_index as usize) }).pos.x,
-                        (unsafe { *(unsafe { *res }).vertices.wrapping_add(vertex_index as usize) }).pos.y,
-                        (unsafe { *(unsafe { *res }).vertices.wrapping_add(vertex_index as usize) }).pos.z,
-                    ]},
-                    normal: {[
-                        (unsafe { *(unsafe { *res }).vertices.wrapping_add(vertex_index as usize) }).normal.x,
-                        (unsafe { *(unsafe { *res }).vertices.wrapping_add(vertex_index as usize) }).normal.y,
-                        (unsafe { *(unsafe { *res }).vertices.wrapping_add(vertex_index as usize) }).normal.z,
-                    ]},
-                    mat: {
-                        (unsafe { *(unsafe { *res }).vertices.wrapping_add(vertex_index as usize) }).palette_index as u8
-                    }
-                });
+        }
+
+        ChunkChanges {loaded, unloaded}
+    }
+
+    ///walks .vox scene graph from node and collects every shape's models with accumulated rotation and translation
+    /// only first frame of animated transforms is used, hidden layers are skipped
+    fn collect_instances(scene: &DotVoxData, node: u32, rot: Mat3, trans: Vec3, out: &mut Vec<(usize, Mat3, Vec3)>) {
+        match &scene.scenes[node as usize] {
+            SceneNode::Transform {frames, child, layer_id, ..} => {
+                let hidden = scene.layers.get(*layer_id as usize).map_or(false, |layer| layer.hidden());
+                if hidden {return;}
+
+                let (local_rot, local_trans) = match frames.first() {
+                    Some(frame) => (
+                        frame.orientation().map_or(Mat3::IDENTITY, |r| Mat3::from_cols_array_2d(&r.to_cols_array_2d())),
+                        frame.position().map_or(Vec3::ZERO, |p| Vec3::new(p.x as f32, p.y as f32, p.z as f32)),
+                    ),
+                    None => (Mat3::IDENTITY, Vec3::ZERO),
+                };
+                World::collect_instances(scene, *child, rot * local_rot, trans + rot * local_trans, out);
+            }
+            SceneNode::Group {children, ..} => {
+                for child in children {
+                    World::collect_instances(scene, *child, rot, trans, out);
+                }
+            }
+            SceneNode::Shape {models, ..} => {
+                for model in models {
+                    out.push((model.model_id as usize, rot, trans));
+                }
+            }
+        }
+    }
+
+    ///places every model instance from scene graph into world blocks (one voxel = one unit), then puts those blocks into palette
+    /// files without scene graph get all models at origin
+    fn load_models(&mut self, scene: &DotVoxData) -> Result<(), VoxLoadError> {
+        // scene.materials
+        let mut instances = Vec::new();
+        if scene.scenes.is_empty() {
+            instances.extend((0..scene.models.len()).map(|i| (i, Mat3::IDENTITY, Vec3::ZERO)));
+        } else {
+            World::collect_instances(scene, 0, Mat3::IDENTITY, Vec3::ZERO, &mut instances);
+        }
+
+        //blocks touched by models, keyed by block position in world. Mesher is override of last model written into it
+        let mut blocks: HashMap<IVec3, (VoxelBlock, Option<Mesher>)> = HashMap::new();
+        for (model_i, rot, trans) in instances {
+            let Some(model) = scene.models.get(model_i) else {continue;};
+            let mesher = self.model_meshers.get(&model_i).copied();
+            //MagicaVoxel rotates around model center
+            let center = Vec3::new(model.size.x as f32, model.size.y as f32, model.size.z as f32) / 2.0;
+
+            for voxel in &model.voxels{
+                let local = Vec3::new(voxel.x as f32, voxel.y as f32, voxel.z as f32) + 0.5 - center;
+                let pos = (rot * local + trans).floor().as_ivec3();
+                let block_pos = pos.div_euclid(IVec3::splat(BLOCK_SIZE as i32));
+                let in_block = pos.rem_euclid(IVec3::splat(BLOCK_SIZE as i32));
+                let (x, y, z) = (in_block.x as usize, in_block.y as usize, in_block.z as usize);
+
+                let entry = blocks.entry(block_pos).or_insert_with(|| (VoxelBlock::new(), None));
+                entry.0.data[x + BLOCK_SIZE*y + BLOCK_SIZE*BLOCK_SIZE*z] = VoxelID::from_palette_index(voxel.i);
+                if mesher.is_some() {entry.1 = mesher;}
+            }
+        }
+
+        for (block_pos, (current_block, mesher)) in blocks {
+            let block_id = self.insert_block(&*current_block.data)
+                .map_err(|e| match e {
+                    EditError::BlockPaletteFull {limit} => VoxLoadError::TooManyBlocks {limit},
+                })?;
+            if let Some(mesher) = mesher {
+                self.block_meshers.insert(block_id, mesher);
             }
+            self.set_block(block_pos, block_id);
+        };
+        Ok(())
+    }
+
+    ///fills voxel_palette from RGBA and MATL chunks
+    /// voxel_palette[0] stays empty, voxel_palette[VoxelID::from_palette_index(i)] is scene.palette[i]
+    fn load_palette(&mut self, scene: &DotVoxData) {
+        for (i, color) in scene.palette.iter().take(255).enumerate() {
+            self.voxel_palette[VoxelID::from_palette_index(i as u8).0 as usize].color = Vec4::new(color.r as f32, color.g as f32, color.b as f32, color.a as f32) / 255.0;
+        }
+
+        //MATL ids are file palette indices (1..255), so they are VoxelIDs already
+        for material in &scene.materials {
+            let Some(target) = self.voxel_palette.get_mut(material.id as usize) else {continue;};
+            if material.id == 0 {continue;}
+            let material_type = material.material_type().unwrap_or("_diffuse");
+            let get = |key: &str| material.properties.get(key).and_then(|v| v.parse::<f32>().ok());
+
+            target.roughness = material.roughness().unwrap_or(0.0);
+            target.emmitance = match material_type {
+                "_emit" => material.emission().unwrap_or(0.0) * (1.0 + material.radiant_flux().unwrap_or(0.0)),
+                _ => 0.0,
+            };
+            target.metalness = match material_type {
+                "_metal" => material.metalness().unwrap_or(0.0),
+                _ => 0.0,
+            };
+            target.transparency = match material_type {
+                "_glass" | "_blend" => material.transparency().unwrap_or(0.0),
+                _ => 0.0,
+            };
+            //_ri is actual index, _ior is stored as ior-1
+            target.ior = get("_ri").or(get("_ior").map(|ior| ior + 1.0)).unwrap_or(1.0);
         }
     }
+
+    ///loads map and meshes chunks around current_origin with self.mesher (or model_meshers override)
+    pub fn load_map(&mut self, scene: &DotVoxData) -> Result<(), VoxLoadError> {
+        self.load_palette(scene);
+        self.load_models(scene)?;
+        self.stream_around(self.current_origin);
+        Ok(())
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loader::load_vox;
+
+    const SCENE: &str = "assets/scene.vox";
+
+    fn load_world(mesher: Mesher) -> (World, DotVoxData) {
+        let scene = load_vox(SCENE).unwrap();
+        let mut world = World::new();
+        world.mesher = mesher;
+        world.load_map(&scene).unwrap();
+        (world, scene)
+    }
+
+    fn all_chunk_data(world: &World) -> Vec<&[BlockID; CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE]> {
+        world.chunks.values().map(|chunk| &*chunk.data)
+            .chain(world.stored_chunks.values().map(|data| &**data))
+            .collect()
+    }
+
+    #[test]
+    fn every_voxel_is_stored() {
+        let (world, scene) = load_world(Mesher::BlockMeshVisible);
+
+        let mut instances = Vec::new();
+        World::collect_instances(&scene, 0, Mat3::IDENTITY, Vec3::ZERO, &mut instances);
+        //scene.vox has two instances at the same place, they fill the same voxels
+        instances.dedup_by(|a, b| a == b);
+        let expected: usize = instances.iter().map(|(model, _, _)| scene.models[*model].voxels.len()).sum();
+
+        let stored: usize = all_chunk_data(&world).iter()
+            .flat_map(|data| data.iter())
+            .filter(|id| id.0 != 0)
+            .map(|id| world.get_block_from_palette(*id).iter().filter(|v| !v.is_empty()).count())
+            .sum();
+        assert_eq!(stored, expected);
+    }
+
+    #[test]
+    fn palette_index_zero_is_not_empty() {
+        assert_eq!(VoxelID::from_palette_index(0), VoxelID(1));
+        assert!(!VoxelID::from_palette_index(0).is_empty());
+    }
+
+    #[test]
+    fn mesh_materials_are_voxel_ids() {
+        for mesher in [Mesher::BlockMeshVisible, Mesher::OgtSimple] {
+            let (world, scene) = load_world(mesher);
+            let used: Vec<u8> = scene.models.iter()
+                .flat_map(|model| model.voxels.iter())
+                .map(|voxel| VoxelID::from_palette_index(voxel.i).0)
+                .collect();
+
+            let mut vertex_count = 0;
+            for chunk in world.chunks.values() {
+                for vertex in &chunk.mesh.vertices {
+                    assert!(used.contains(&vertex.mat), "{:?} produced mat {} that is not in scene", mesher, vertex.mat);
+                    vertex_count += 1;
+                }
+            }
+            assert!(vertex_count > 0);
+        }
+    }
+
+    #[test]
+    fn chunk_meshes_are_indexed() {
+        for mesher in Mesher::ALL {
+            let (world, _) = load_world(mesher);
+            let (vertices, indices) = world.chunks.values()
+                .fold((0, 0), |(v, i), chunk| (v + chunk.mesh.vertices.len(), i + chunk.mesh.indices.len()));
+            assert!(indices > 0 && indices % 3 == 0, "{:?}", mesher);
+            for chunk in world.chunks.values() {
+                assert!(chunk.mesh.indices.iter().all(|i| (*i as usize) < chunk.mesh.vertices.len()), "{:?} index out of range", mesher);
+            }
+            //quads share at least their diagonal
+            assert!(vertices * 3 <= indices * 2, "{:?}: {} vertices for {} indices", mesher, vertices, indices);
+        }
+    }
+
+    #[test]
+    fn chunk_voxels_match_get_voxel() {
+        let (world, _) = load_world(Mesher::OgtSimple);
+        let side = (CHUNK_SIZE*BLOCK_SIZE) as i32;
+        for chunk_pos in world.chunks.keys() {
+            let voxels = world.chunk_voxels(*chunk_pos).unwrap();
+            assert!(voxels.iter().any(|voxel| !voxel.is_empty()));
+            for (i, voxel) in voxels.iter().enumerate() {
+                let local = IVec3::new(i as i32 % side, i as i32 / side % side, i as i32 / (side*side));
+                assert_eq!(*voxel, world.get_voxel(*chunk_pos * side + local));
+            }
+        }
+        assert!(world.chunk_voxels(IVec3::splat(1000)).is_none());
+    }
+
+    #[test]
+    fn chunk_blocks_cover_only_their_chunk() {
+        let chunk_pos = IVec3::new(-1, 2, 0);
+        let blocks: Vec<IVec3> = World::chunk_blocks(chunk_pos).collect();
+        assert_eq!(blocks.len(), CHUNK_SIZE*CHUNK_SIZE*CHUNK_SIZE);
+        for (i, pos) in blocks.iter().enumerate() {
+            let (chunk, local) = World::split_block_pos(*pos);
+            assert_eq!(chunk, chunk_pos);
+            assert_eq!(local, IVec3::new((i % CHUNK_SIZE) as i32, (i / CHUNK_SIZE % CHUNK_SIZE) as i32, (i / (CHUNK_SIZE*CHUNK_SIZE)) as i32));
+        }
+    }
+
+    ///voxel every triangle of loaded meshes lies on (just behind its centroid) and mat of its first vertex
+    fn triangle_voxels(world: &World) -> Vec<(VoxelID, u8)> {
+        world.chunks.values().flat_map(|chunk| chunk.mesh.indices.chunks(3).map(move |triangle| {
+            let vertices: Vec<MyVertex> = triangle.iter().map(|i| chunk.mesh.vertices[*i as usize]).collect();
+            let centroid = vertices.iter().map(|v| Vec3::from(v.position)).sum::<Vec3>() / 3.0;
+            let behind = chunk.mesh.trans.transform_point3(centroid - Vec3::from(vertices[0].normal) * 0.5);
+            (world.get_voxel(behind.floor().as_ivec3()), vertices[0].mat)
+        })).collect()
+    }
+
+    #[test]
+    fn greedy_quads_keep_their_material() {
+        for mesher in [Mesher::BlockMeshGreedy, Mesher::OgtGreedy] {
+            let (world, _) = load_world(mesher);
+            for (voxel, mat) in triangle_voxels(&world) {
+                assert_eq!(voxel.0, mat, "{:?} merged faces of different colors", mesher);
+            }
+        }
+
+        let (world, _) = load_world(Mesher::BlockMeshGreedyAcrossColors);
+        let across = triangle_voxels(&world);
+        assert!(across.iter().all(|(voxel, mat)| !voxel.is_empty() && *mat == crate::mesher::MAT_FROM_VOLUME));
+        let (world, _) = load_world(Mesher::BlockMeshGreedy);
+        assert!(across.len() <= triangle_voxels(&world).len());
+    }
+
+    #[test]
+    fn set_voxel_in_empty_space() {
+        let (mut world, _) = load_world(Mesher::OgtSimple);
+        let pos = IVec3::new(-500, 40, 3);
+        assert!(world.get_voxel(pos).is_empty());
+
+        world.set_voxel(pos, VoxelID(7)).unwrap();
+        assert_eq!(world.get_voxel(pos), VoxelID(7));
+        assert!(world.get_voxel(pos + IVec3::X).is_empty());
+
+        let remeshed = world.remesh_dirty();
+        let (chunk_pos, _) = World::split_block_pos(pos.div_euclid(IVec3::splat(BLOCK_SIZE as i32)));
+        assert_eq!(remeshed.chunks, vec![chunk_pos]);
+        //lone voxel is a cube, 6 faces of 2 triangles. Faces point different ways, so they share no vertices
+        let mesh = &world.chunks[&chunk_pos].mesh;
+        assert_eq!(mesh.indices.len(), 36);
+        assert_eq!(mesh.vertices.len(), 24);
+        assert!(mesh.vertices.iter().all(|v| v.mat == 7));
+
+        assert!(world.remesh_dirty().is_empty(), "nothing changed since last remesh");
+    }
+
+    #[test]
+    fn edit_at_removes_and_places_where_ray_hits() {
+        let (mut world, _) = load_world(Mesher::OgtSimple);
+        world.set_voxel(IVec3::new(-500, 40, 3), VoxelID(7)).unwrap();
+        world.remesh_dirty();
+        //straight down onto that voxel, through empty space above it
+        let ray = Ray::new(Vec3::new(-499.5, 40.5, 20.0), -Vec3::Z);
+
+        assert_eq!(world.edit_at(&ray, VoxelEdit::Place).unwrap(), Some(IVec3::new(-500, 40, 4)));
+        assert_eq!(world.get_voxel(IVec3::new(-500, 40, 4)), VoxelID(7));
+        assert_eq!(world.edit_at(&ray, VoxelEdit::Remove).unwrap(), Some(IVec3::new(-500, 40, 4)));
+        assert_eq!(world.edit_at(&ray, VoxelEdit::Remove).unwrap(), Some(IVec3::new(-500, 40, 3)));
+        assert!(world.get_voxel(IVec3::new(-500, 40, 3)).is_empty());
+        assert!(!world.remesh_dirty().is_empty());
+
+        //nothing left under it
+        assert_eq!(world.edit_at(&ray, VoxelEdit::Place).unwrap(), None);
+    }
+
+    #[test]
+    fn edit_remeshes_only_its_chunk() {
+        let (mut world, _) = load_world(Mesher::OgtSimple);
+        let before: HashMap<IVec3, usize> = world.chunks.iter().map(|(pos, chunk)| (*pos, chunk.mesh.vertices.len())).collect();
+
+        //any voxel of scene away from block edges, so no neighbour is affected
+        let inner = |pos: IVec3| pos.rem_euclid(IVec3::splat(BLOCK_SIZE as i32)).cmpge(IVec3::ONE).all()
+            && pos.rem_euclid(IVec3::splat(BLOCK_SIZE as i32)).cmplt(IVec3::splat(BLOCK_SIZE as i32 - 1)).all();
+        let pos = (0..).map(|i| IVec3::new(i % 64 - 32, i / 64 % 64 - 32, i / 4096))
+            .take(64*64*64)
+            .find(|pos| inner(*pos) && !world.get_voxel(*pos).is_empty())
+            .expect("scene has voxels near origin");
+        world.set_voxel(pos, VoxelID::EMPTY).unwrap();
+        assert!(world.get_voxel(pos).is_empty());
+
+        let remeshed = world.remesh_dirty();
+        assert_eq!(remeshed.blocks.len(), 1);
+        assert_eq!(remeshed.chunks.len(), 1);
+        for (chunk_pos, chunk) in &world.chunks {
+            if !remeshed.chunks.contains(chunk_pos) {
+                assert_eq!(chunk.mesh.vertices.len(), before[chunk_pos]);
+            }
+        }
+    }
+
+    #[test]
+    fn streaming_loads_and_drops_chunks_around_focus() {
+        let mut world = World::new();
+        let chunk_voxels = (CHUNK_SIZE*BLOCK_SIZE) as i32;
+        let near = IVec3::ZERO;
+        let far = IVec3::new(12, 0, 0);
+        world.set_voxel(near * chunk_voxels, VoxelID(3)).unwrap();
+        world.set_voxel(far * chunk_voxels, VoxelID(4)).unwrap();
+        world.remesh_dirty();
+        assert!(world.chunks.contains_key(&near));
+        assert!(world.stored_chunks.contains_key(&far), "chunk past WORLD_SIZE/2 is stored, not loaded");
+
+        let focus = World::chunk_at((far * chunk_voxels).as_vec3() - Vec3::new(2.5, 0.0, 0.0) * chunk_voxels as f32);
+        assert_eq!(focus, IVec3::new(9, 0, 0));
+        let changes = world.stream_around(focus);
+        assert_eq!(changes.loaded, vec![far]);
+        assert_eq!(changes.unloaded, vec![near]);
+        assert!(world.stored_chunks.contains_key(&near));
+        assert!(!world.chunks[&far].mesh.vertices.is_empty(), "loaded chunk is meshed");
+        assert_eq!(world.get_voxel(near * chunk_voxels), VoxelID(3), "unloaded chunk keeps its voxels");
+
+        assert!(world.stream_around(focus).is_empty(), "same focus changes nothing");
+        let changes = world.stream_around(IVec3::ZERO);
+        assert_eq!((changes.loaded, changes.unloaded), (vec![near], vec![far]));
+    }
+
+    ///solid box 48x16x16 voxels: 3 blocks in a row, last one in the next chunk
+    fn three_block_box() -> World {
+        let mut world = World::new();
+        let size = (CHUNK_SIZE*BLOCK_SIZE) as i32;
+        for x in size-32..size+16 {
+        for y in 0..16 {
+        for z in 0..16 {
+            world.set_voxel(IVec3::new(x, y, z), VoxelID(2)).unwrap();
+        }
+        }
+        }
+        world.remesh_dirty();
+        world
+    }
+
+    ///triangles (in world space) lying on plane x = x
+    fn triangles_at_x(world: &World, x: f32) -> usize {
+        world.chunks.values().flat_map(|chunk| chunk.mesh.indices.chunks(3)
+            .filter(move |triangle| triangle.iter().all(|i| chunk.mesh.trans.transform_point3(Vec3::from(chunk.mesh.vertices[*i as usize].position)).x == x)))
+            .count()
+    }
+
+    #[test]
+    fn faces_between_blocks_are_culled() {
+        let mut world = three_block_box();
+        let chunks: Vec<IVec3> = world.chunks.keys().copied().collect();
+        let size = (CHUNK_SIZE*BLOCK_SIZE) as f32;
+        for mesher in Mesher::ALL {
+            world.mesher = mesher;
+            for chunk_pos in &chunks {
+                world.remesh_chunk(*chunk_pos);
+            }
+            //between blocks of one chunk and between chunks
+            assert_eq!(triangles_at_x(&world, size - 16.0), 0, "{:?}", mesher);
+            assert_eq!(triangles_at_x(&world, size), 0, "{:?}", mesher);
+            assert!(triangles_at_x(&world, size - 32.0) > 0, "{:?}", mesher);
+        }
+        world.mesher = Mesher::OgtSimple;
+        for chunk_pos in &chunks {
+            world.remesh_chunk(*chunk_pos);
+        }
+        //only the outside of the box, 2 triangles per voxel face
+        let indices: usize = world.chunks.values().map(|chunk| chunk.mesh.indices.len()).sum();
+        assert_eq!(indices, 2 * (48*16*2 + 48*16*2 + 16*16*2) * 3);
+    }
+
+    #[test]
+    fn edit_on_block_edge_remeshes_neighbour() {
+        let mut world = three_block_box();
+        let indices = |world: &World, chunk_pos: IVec3| world.chunks[&chunk_pos].mesh.indices.len();
+        let (first, second) = (IVec3::ZERO, IVec3::X);
+        let before = (indices(&world, first), indices(&world, second));
+
+        //first voxel of second chunk, its hole shows a face of last voxel of first one
+        world.set_voxel(IVec3::new((CHUNK_SIZE*BLOCK_SIZE) as i32, 5, 5), VoxelID::EMPTY).unwrap();
+        let remeshed = world.remesh_dirty();
+        assert_eq!(remeshed.blocks, vec![IVec3::new(CHUNK_SIZE as i32, 0, 0)]);
+        assert!(remeshed.chunks.contains(&first) && remeshed.chunks.contains(&second));
+        assert_eq!(indices(&world, first), before.0 + 6);
+        assert_eq!(indices(&world, second), before.1 + 5*6);
+    }
+
+    #[test]
+    fn background_meshing_matches_in_place() {
+        let scene = load_vox(SCENE).unwrap();
+        let edit = IVec3::new(3, 2, 1);
+        let (mut world, _) = load_world(Mesher::OgtGreedy);
+        world.set_voxel(edit, VoxelID(9)).unwrap();
+        world.remesh_dirty();
+
+        let mut background = World::new();
+        background.mesher = Mesher::OgtGreedy;
+        background.workers = Some(MeshWorkers::new(3));
+        background.load_map(&scene).unwrap();
+        //edited while its first mesh may still be on the way, that one must not win
+        background.set_voxel(edit, VoxelID(9)).unwrap();
+        let start = std::time::Instant::now();
+        while !background.remesh_dirty().chunks.is_empty() || background.meshing() > 0 {
+            assert!(start.elapsed().as_secs() < 60, "workers did not finish");
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+
+        assert_eq!(world.chunks.len(), background.chunks.len());
+        for (pos, chunk) in &world.chunks {
+            let other = &background.chunks[pos].mesh;
+            assert_eq!(chunk.mesh.indices, other.indices, "chunk {}", pos);
+            let positions = |mesh: &MeshCPU| mesh.vertices.iter().map(|v| (v.position, v.mat, v.ao)).collect::<Vec<_>>();
+            assert_eq!(positions(&chunk.mesh), positions(other), "chunk {}", pos);
+        }
+    }
+
+    #[test]
+    fn identical_blocks_are_shared_and_copied_on_write() {
+        let mut world = World::new();
+        let a = IVec3::new(1000, 0, 0);
+        let b = a + IVec3::X * BLOCK_SIZE as i32;
+        world.set_voxel(a, VoxelID(3)).unwrap();
+        world.set_voxel(b, VoxelID(3)).unwrap();
+        let id = world.get_block(a.div_euclid(IVec3::splat(BLOCK_SIZE as i32)));
+        assert_eq!(world.get_block(b.div_euclid(IVec3::splat(BLOCK_SIZE as i32))), id);
+        assert_eq!(world.block_refs[id.index()], 2);
+
+        world.set_voxel(b + IVec3::Y, VoxelID(4)).unwrap();
+        assert_eq!(world.get_voxel(a + IVec3::Y), VoxelID::EMPTY, "edit of b must not leak into a");
+        assert_eq!(world.get_voxel(b), VoxelID(3));
+        assert_eq!(world.get_voxel(b + IVec3::Y), VoxelID(4));
+        assert_eq!(world.block_refs[id.index()], 1);
+    }
+
+    #[test]
+    fn cleared_block_slot_is_reused() {
+        let mut world = World::new();
+        let pos = IVec3::new(1000, 0, 0);
+        world.set_voxel(pos, VoxelID(3)).unwrap();
+        let used = world.block_count();
+        world.set_voxel(pos, VoxelID::EMPTY).unwrap();
+        assert!(world.get_block(pos.div_euclid(IVec3::splat(BLOCK_SIZE as i32))).is_empty());
+        assert!(world.block_lookup.is_empty());
+
+        world.set_voxel(pos + IVec3::ONE * 64, VoxelID(5)).unwrap();
+        assert_eq!(world.block_count(), used, "freed slot should be taken before a new one");
+    }
+
+    #[test]
+    fn palette_grows_past_256_blocks() {
+        let mut world = World::new();
+        //every block gets different voxel, so none of them can be shared
+        for i in 0..300 {
+            let pos = IVec3::new(i * BLOCK_SIZE as i32, 0, 0) + IVec3::new(i / 256, i % 16, i / 16 % 16);
+            world.set_voxel(pos, VoxelID(1)).unwrap();
+        }
+        assert_eq!(world.block_count(), 301);
+        assert_eq!(world.get_voxel(IVec3::new(299 * BLOCK_SIZE as i32 + 1, 299 % 16, 299 / 16 % 16)), VoxelID(1));
+    }
+
+    #[test]
+    fn block_limit_is_reported() {
+        let mut world = World::new();
+        world.max_blocks = 3;
+        world.set_voxel(IVec3::new(0, 0, 0), VoxelID(1)).unwrap();
+        world.set_voxel(IVec3::new(16, 0, 0), VoxelID(2)).unwrap();
+        match world.set_voxel(IVec3::new(32, 0, 0), VoxelID(3)) {
+            Err(EditError::BlockPaletteFull {limit}) => assert_eq!(limit, 3),
+            other => panic!("expected full palette, got {:?}", other),
+        }
+        assert!(world.get_voxel(IVec3::new(32, 0, 0)).is_empty());
+
+        let mut world = World::new();
+        //only empty block fits
+        world.max_blocks = 1;
+        let err = world.load_map(&load_vox(SCENE).unwrap()).unwrap_err();
+        assert!(matches!(err, VoxLoadError::TooManyBlocks {limit: 1}), "{}", err);
+    }
+
+    #[test]
+    fn failed_edit_keeps_block_refs() {
+        let mut world = World::new();
+        world.max_blocks = 3;
+        //two places share one block, third block fills palette
+        world.set_voxel(IVec3::new(0, 0, 0), VoxelID(1)).unwrap();
+        world.set_voxel(IVec3::new(16, 0, 0), VoxelID(1)).unwrap();
+        world.set_voxel(IVec3::new(32, 0, 0), VoxelID(2)).unwrap();
+        let refs = world.block_refs.clone();
+
+        //copy of shared block has nowhere to go
+        assert!(world.set_voxel(IVec3::new(1, 0, 0), VoxelID(1)).is_err());
+        assert_eq!(world.block_refs, refs);
+        assert_eq!(world.get_voxel(IVec3::new(0, 0, 0)), VoxelID(1));
+        //empty block is not reference counted
+        assert!(world.set_voxel(IVec3::new(48, 0, 0), VoxelID(3)).is_err());
+        assert_eq!(world.block_refs, refs);
+    }
+}